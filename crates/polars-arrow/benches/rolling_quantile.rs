@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use polars_arrow::legacy::kernels::rolling::no_nulls::rolling_quantile;
+use polars_arrow::legacy::kernels::rolling::{counting_sort, RollingQuantileParams};
+use polars_arrow::legacy::kernels::{DynArgs, QuantileInterpolOptions};
+use polars_utils::total_ord::TotalOrd;
+
+/// `len` values that cycle through `n_distinct` values in a scrambled order.
+fn few_distinct(len: usize, n_distinct: usize) -> Vec<f64> {
+    (0..len)
+        .map(|i| ((i.wrapping_mul(2654435761) >> 7) % n_distinct) as f64)
+        .collect()
+}
+
+fn bench_sort_window(c: &mut Criterion) {
+    for window_size in [100, 1_000, 10_000] {
+        for n_distinct in [2, 8] {
+            let window = few_distinct(window_size, n_distinct);
+            let id = format!("window {window_size} distinct {n_distinct}");
+
+            c.bench_function(&format!("counting_sort {id}"), |b| {
+                b.iter_batched_ref(
+                    || window.clone(),
+                    |buf| assert!(counting_sort(buf)),
+                    BatchSize::SmallInput,
+                )
+            });
+            c.bench_function(&format!("comparison_sort {id}"), |b| {
+                b.iter_batched_ref(
+                    || window.clone(),
+                    |buf| buf.sort_by(TotalOrd::tot_cmp),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+}
+
+/// `len` values in runs of `run_len` equal values, so the value that enters a window
+/// mostly equals the value that leaves it.
+fn step_wise(len: usize, run_len: usize) -> Vec<f64> {
+    (0..len).map(|i| (i / run_len) as f64).collect()
+}
+
+fn median() -> DynArgs {
+    Some(Arc::new(RollingQuantileParams {
+        prob: 0.5,
+        interpol: QuantileInterpolOptions::Linear,
+    }))
+}
+
+fn bench_unchanged_windows(c: &mut Criterion) {
+    let len = 1_000_000;
+    for window_size in [10, 100, 1_000] {
+        // Both inputs have few distinct values, but only in the step-wise one do most
+        // windows hold the same values as the window before them.
+        let unchanged = step_wise(len, 10 * window_size);
+        let changing = few_distinct(len, 8);
+
+        c.bench_function(
+            &format!("rolling_quantile unchanged windows {window_size}"),
+            |b| {
+                b.iter(|| {
+                    rolling_quantile(&unchanged, window_size, window_size, false, None, median())
+                })
+            },
+        );
+        c.bench_function(
+            &format!("rolling_quantile changing windows {window_size}"),
+            |b| {
+                b.iter(|| {
+                    rolling_quantile(&changing, window_size, window_size, false, None, median())
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, bench_sort_window, bench_unchanged_windows);
+criterion_main!(benches);
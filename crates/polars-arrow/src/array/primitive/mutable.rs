@@ -234,6 +234,17 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.update_all_valid();
     }
 
+    /// Extends the [`MutablePrimitiveArray`] from an iterator of values.
+    /// This differs from `extend_trusted_len_values` in that the iterator doesn't need a trusted length.
+    #[inline]
+    pub fn extend_values<I>(&mut self, iterator: I)
+    where
+        I: Iterator<Item = T>,
+    {
+        self.values.extend(iterator);
+        self.update_all_valid();
+    }
+
     #[inline]
     /// Extends the [`MutablePrimitiveArray`] from a slice
     pub fn extend_from_slice(&mut self, items: &[T]) {
@@ -0,0 +1,51 @@
+use crate::types::NativeType;
+
+/// The index of the power of two bucket of `v`: bucket `k` holds the values in `[2^k, 2^(k+1))`.
+///
+/// Values beyond the last of the `num_buckets` buckets go into the last bucket, zero has no
+/// bucket.
+#[inline]
+pub fn power_of_two_bucket_idx(v: u64, num_buckets: u32) -> Option<u32> {
+    if v == 0 || num_buckets == 0 {
+        return None;
+    }
+    let k = u64::BITS - 1 - v.leading_zeros();
+    Some(std::cmp::min(k, num_buckets - 1))
+}
+
+/// The power of two bucket of every value of `slice`, see [`power_of_two_bucket_idx`].
+pub fn power_of_two_bucket<T>(slice: &[T], num_buckets: u32) -> Vec<Option<u32>>
+where
+    T: NativeType + PartialOrd + Into<u64>,
+{
+    slice
+        .iter()
+        .map(|v| power_of_two_bucket_idx((*v).into(), num_buckets))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_of_two_bucket() {
+        let values = [0u32, 1, 2, 3, 4, 7, 8, 1000];
+        assert_eq!(
+            power_of_two_bucket(&values, 4),
+            &[
+                None,
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(3),
+                Some(3)
+            ]
+        );
+        assert_eq!(power_of_two_bucket_idx(u64::MAX, 64), Some(63));
+        assert_eq!(power_of_two_bucket_idx(1 << 40, 64), Some(40));
+        assert_eq!(power_of_two_bucket_idx(5, 0), None);
+    }
+}
@@ -0,0 +1,75 @@
+use num_traits::AsPrimitive;
+use polars_utils::float::IsFloat;
+
+use crate::types::NativeType;
+
+/// The numerical gradient of `y` over the sample points `x`, as computed by NumPy's `gradient`.
+///
+/// Interior points use second order accurate central differences, the first and last point use
+/// first order forward and backward differences. If `x` is `None` the samples are assumed to have
+/// unit spacing.
+///
+/// # Panics
+/// Panics if `y` has a single value, or if `x` is given and does not have the same length as `y`.
+pub fn gradient<T>(y: &[T], x: Option<&[T]>) -> Vec<f64>
+where
+    T: NativeType + IsFloat + AsPrimitive<f64>,
+{
+    let n = y.len();
+    if n == 0 {
+        return vec![];
+    }
+    assert!(n >= 2, "the gradient needs at least two values");
+    let y = |i: usize| -> f64 { y[i].as_() };
+
+    let mut out = Vec::with_capacity(n);
+    match x {
+        Some(x) => {
+            assert_eq!(x.len(), n);
+            let x = |i: usize| -> f64 { x[i].as_() };
+            out.push((y(1) - y(0)) / (x(1) - x(0)));
+            for i in 1..n - 1 {
+                let hs = x(i) - x(i - 1);
+                let hd = x(i + 1) - x(i);
+                let numerator =
+                    hs * hs * y(i + 1) + (hd * hd - hs * hs) * y(i) - hd * hd * y(i - 1);
+                out.push(numerator / (hs * hd * (hd + hs)));
+            }
+            out.push((y(n - 1) - y(n - 2)) / (x(n - 1) - x(n - 2)));
+        },
+        None => {
+            out.push(y(1) - y(0));
+            for i in 1..n - 1 {
+                out.push(0.5 * (y(i + 1) - y(i - 1)));
+            }
+            out.push(y(n - 1) - y(n - 2));
+        },
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(gradient::<f64>(&[], None), &[] as &[f64]);
+        // Values from `np.gradient`.
+        assert_eq!(
+            gradient(&[1.0, 2.0, 4.0, 7.0, 11.0, 16.0], None),
+            &[1.0, 1.5, 2.5, 3.5, 4.5, 5.0]
+        );
+        assert_eq!(gradient(&[1i32, 3], None), &[2.0, 2.0]);
+        assert_eq!(
+            gradient(&[1.0, 2.0, 4.0, 7.0], Some(&[0.0, 1.0, 1.5, 3.5])),
+            &[1.0, 3.0, 3.5, 1.5]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gradient_single_value() {
+        gradient(&[1.0f32], None);
+    }
+}
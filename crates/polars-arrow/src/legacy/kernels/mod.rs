@@ -5,13 +5,18 @@ use crate::bitmap::utils::BitChunks;
 #[cfg(feature = "simd")]
 pub mod agg_mean;
 pub mod atan2;
+pub mod bucket;
 pub mod concatenate;
 pub mod ewm;
 pub mod float;
+pub mod gradient;
 pub mod list;
 pub mod list_bytes_iter;
 pub mod pow;
+mod quantile;
+#[cfg(feature = "rolling")]
 pub mod rolling;
+mod rolling_params;
 pub mod set;
 pub mod sort_partition;
 #[cfg(feature = "performant")]
@@ -20,7 +25,11 @@ pub mod sorted_join;
 pub mod string;
 pub mod take_agg;
 mod time;
+pub mod trapz;
+pub mod unique;
 
+pub use quantile::{quantile_index, QuantileInterpolOptions};
+pub use rolling_params::{DynArgs, RollingQuantileParams, RollingVarParams};
 pub use time::Ambiguous;
 #[cfg(feature = "timezones")]
 pub use time::{convert_to_naive_local, convert_to_naive_local_opt};
@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use polars_error::{polars_bail, PolarsError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How to compute a quantile that lies between two values.
+///
+/// This is shared by `Series::quantile`, the group-by quantile aggregation and the rolling
+/// quantile kernels, so that they accept the same names.
+///
+/// Use it through `polars_core::prelude`; it is only defined here because the rolling kernels
+/// of this crate take it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuantileInterpolOptions {
+    #[default]
+    Nearest,
+    Lower,
+    Higher,
+    Midpoint,
+    Linear,
+}
+
+impl FromStr for QuantileInterpolOptions {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nearest" => Ok(QuantileInterpolOptions::Nearest),
+            "lower" => Ok(QuantileInterpolOptions::Lower),
+            "higher" => Ok(QuantileInterpolOptions::Higher),
+            "midpoint" => Ok(QuantileInterpolOptions::Midpoint),
+            "linear" => Ok(QuantileInterpolOptions::Linear),
+            s => polars_bail!(InvalidOperation:
+                "invalid interpolation {}, expected one of: \"nearest\", \"lower\", \"higher\", \"midpoint\", \"linear\"", s
+            ),
+        }
+    }
+}
+
+impl Display for QuantileInterpolOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            QuantileInterpolOptions::Nearest => "nearest",
+            QuantileInterpolOptions::Lower => "lower",
+            QuantileInterpolOptions::Higher => "higher",
+            QuantileInterpolOptions::Midpoint => "midpoint",
+            QuantileInterpolOptions::Linear => "linear",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Position of the `quantile` in `len` sorted values.
+///
+/// Returns the index of the lower value, the index of the upper value and the weight of the
+/// upper value, which is only used if the two indices differ; see
+/// [`lerp`](polars_utils::functions::lerp). This is
+/// shared by `Series::quantile` and the rolling quantile kernels so that both agree exactly.
+pub fn quantile_index(
+    len: usize,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> (usize, usize, f64) {
+    debug_assert!(len > 0);
+    let float_idx = quantile_float_idx(len, quantile);
+    let idx = match interpol {
+        QuantileInterpolOptions::Nearest => ((len as f64) * quantile) as usize,
+        QuantileInterpolOptions::Lower
+        | QuantileInterpolOptions::Midpoint
+        | QuantileInterpolOptions::Linear => float_idx.floor() as usize,
+        QuantileInterpolOptions::Higher => float_idx.ceil() as usize,
+    };
+    let idx = std::cmp::min(idx, len - 1);
+
+    let top_idx = std::cmp::min(float_idx.ceil() as usize, len - 1);
+    match interpol {
+        QuantileInterpolOptions::Midpoint if top_idx != idx => (idx, top_idx, 0.5),
+        QuantileInterpolOptions::Linear if top_idx != idx => {
+            (idx, top_idx, quantile_proportion(float_idx, idx))
+        },
+        _ => (idx, idx, 0.0),
+    }
+}
+
+/// Position of the `quantile` in `len` sorted values.
+///
+/// Positions within rounding error of an index are snapped onto that index, so a
+/// quantile that lands on an element returns that element without interpolating.
+fn quantile_float_idx(len: usize, quantile: f64) -> f64 {
+    let float_idx = (len as f64 - 1.0) * quantile;
+    let rounded = float_idx.round();
+    if (float_idx - rounded).abs() <= float_idx * f64::EPSILON {
+        rounded
+    } else {
+        float_idx
+    }
+}
+
+/// The weight of the upper value when linearly interpolating at `float_idx` between `idx`
+/// and the next index.
+///
+/// This lies in `[0, 1)` by construction. Release builds clamp it, so that an error in the
+/// index computation can't extrapolate beyond the two values.
+fn quantile_proportion(float_idx: f64, idx: usize) -> f64 {
+    let proportion = float_idx - idx as f64;
+    debug_assert!(
+        (0.0..1.0).contains(&proportion),
+        "quantile interpolation proportion {proportion} is not in [0, 1)"
+    );
+    proportion.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpol_roundtrip() {
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            let parsed: QuantileInterpolOptions = interpol.to_string().parse().unwrap();
+            assert_eq!(parsed, interpol);
+        }
+        assert!("interpolated".parse::<QuantileInterpolOptions>().is_err());
+    }
+}
@@ -2,19 +2,28 @@ pub mod no_nulls;
 pub mod nulls;
 mod window;
 
+#[cfg(test)]
 use std::any::Any;
 use std::cmp::Ordering;
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+#[cfg(test)]
 use std::sync::Arc;
 
 use num_traits::{Bounded, Float, NumCast, One, Zero};
-use polars_utils::float::IsFloat;
+use polars_utils::float::{propagate_nan_max, propagate_nan_min, IsFloat};
 use polars_utils::ord::{compare_fn_nan_max, compare_fn_nan_min};
+use polars_utils::try_cast_num;
+// only public for the benchmarks
+#[doc(hidden)]
+pub use window::counting_sort;
 use window::*;
 
-use crate::array::{ArrayRef, PrimitiveArray};
+pub use super::rolling_params::*;
+use crate::array::{ArrayRef, BooleanArray, MutablePrimitiveArray, PrimitiveArray};
 use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::legacy::kernels::quantile_index;
 use crate::legacy::prelude::*;
+use crate::legacy::trusted_len::TrustedLen;
 use crate::legacy::utils::CustomIterTools;
 use crate::types::NativeType;
 
@@ -23,7 +32,6 @@ type End = usize;
 type Idx = usize;
 type WindowSize = usize;
 type Len = usize;
-pub type DynArgs = Option<Arc<dyn Any + Sync + Send>>;
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
     (i.saturating_sub(window_size - 1), i + 1)
@@ -36,54 +44,309 @@ fn det_offsets_center(i: Idx, window_size: WindowSize, len: Len) -> (usize, usiz
     )
 }
 
-fn create_validity<Fo>(
+/// The index of the quantile in a sorted window of `length` values for the interpolation
+/// methods that select one of the values: `Lower`, `Higher` and `Nearest`.
+///
+/// Returns `None` for `Linear` and `Midpoint`, which combine two values.
+fn quantile_select_idx(
+    length: usize,
+    prob: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<usize> {
+    match interpol {
+        QuantileInterpolOptions::Linear | QuantileInterpolOptions::Midpoint => None,
+        _ => Some(quantile_index(length, prob, interpol).0),
+    }
+}
+
+/// The offsets function of trailing windows, or of windows centered on their output if
+/// `center` is set.
+fn offsets_fn(center: bool) -> fn(Idx, WindowSize, Len) -> (Start, End) {
+    if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    }
+}
+
+/// The `(start, end)` offsets of the window of every output of a rolling aggregation over
+/// `len` values.
+///
+/// Windows trail their output, or are centered on it if `center` is set. Windows at the
+/// boundaries are truncated, so they may hold fewer than `window_size` values.
+pub fn window_offsets(
+    len: usize,
+    window_size: usize,
+    center: bool,
+) -> impl TrustedLen<Item = (usize, usize)> + Clone {
+    let offsets_fn = offsets_fn(center);
+    (0..len).map(move |i| offsets_fn(i, window_size, len))
+}
+
+/// The number of outputs at the head and at the tail of a rolling aggregation over `len`
+/// values whose window holds fewer than `min_periods` values, and that are therefore null.
+pub fn window_invalid_lengths(
+    len: usize,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> (usize, usize) {
+    let too_short = |(start, end): (usize, usize)| end - start < min_periods;
+    let offsets = window_offsets(len, window_size, center);
+    let head = offsets.clone().take_while(|&o| too_short(o)).count();
+    if head == len {
+        return (len, 0);
+    }
+    let offsets_fn = offsets_fn(center);
+    let tail = (head..len)
+        .rev()
+        .take_while(|&i| too_short(offsets_fn(i, window_size, len)))
+        .count();
+    (head, tail)
+}
+
+/// The validity of a rolling aggregation over `len` values, or `None` if every window holds
+/// at least `min_periods` values. See [`window_invalid_lengths`].
+pub fn window_validity(
+    len: usize,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> Option<Bitmap> {
+    create_validity(min_periods, len, window_size, center).map(|b| b.into())
+}
+
+fn create_validity(
     min_periods: usize,
     len: usize,
     window_size: usize,
-    det_offsets_fn: Fo,
-) -> Option<MutableBitmap>
-where
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
-{
+    center: bool,
+) -> Option<MutableBitmap> {
     if min_periods > 1 {
-        let mut validity = MutableBitmap::with_capacity(len);
-        validity.extend_constant(len, true);
-
-        // Set the null values at the boundaries
-
-        // Head.
-        for i in 0..len {
-            let (start, end) = det_offsets_fn(i, window_size, len);
-            if (end - start) < min_periods {
-                validity.set(i, false)
-            } else {
-                break;
+        let (head, tail) = window_invalid_lengths(len, window_size, min_periods, center);
+        Some(invalid_ends_validity(len, head, tail))
+    } else {
+        None
+    }
+}
+
+/// A validity of `len` values of which the first `n_invalid` are null.
+pub fn leading_invalid_validity(len: usize, n_invalid: usize) -> Bitmap {
+    invalid_ends_validity(len, n_invalid, 0).into()
+}
+
+/// Combine an existing `validity` of `len` values with [`leading_invalid_validity`].
+pub fn merge_leading_invalid_validity(
+    validity: Option<&Bitmap>,
+    len: usize,
+    n_invalid: usize,
+) -> Option<Bitmap> {
+    match validity {
+        _ if n_invalid == 0 => validity.cloned(),
+        None => Some(leading_invalid_validity(len, n_invalid)),
+        Some(validity) => {
+            debug_assert_eq!(validity.len(), len);
+            Some(validity & &leading_invalid_validity(len, n_invalid))
+        },
+    }
+}
+
+/// A validity of `len` values of which the first `head` and the last `tail` are null.
+/// Writes whole bytes rather than pushing the bits one by one.
+fn invalid_ends_validity(len: usize, head: usize, tail: usize) -> MutableBitmap {
+    assert!(head + tail <= len);
+    let mut bytes = vec![u8::MAX; (len + 7) / 8];
+    bytes[..head / 8].fill(0);
+    if head % 8 != 0 {
+        bytes[head / 8] = u8::MAX << (head % 8);
+    }
+    // Also clears the padding bits after `len`.
+    let end = len - tail;
+    if end % 8 != 0 {
+        bytes[end / 8] &= !(u8::MAX << (end % 8));
+    }
+    bytes[(end + 7) / 8..].fill(0);
+    MutableBitmap::from_vec(bytes, len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::Array;
+
+    #[test]
+    fn test_window_offsets() {
+        assert_eq!(
+            window_offsets(5, 3, false).collect::<Vec<_>>(),
+            &[(0, 1), (0, 2), (0, 3), (1, 4), (2, 5)]
+        );
+        assert_eq!(
+            window_offsets(5, 3, true).collect::<Vec<_>>(),
+            &[(0, 2), (0, 3), (1, 4), (2, 5), (3, 5)]
+        );
+        assert_eq!(
+            window_offsets(5, 4, true).collect::<Vec<_>>(),
+            &[(0, 2), (0, 3), (0, 4), (1, 5), (2, 5)]
+        );
+        assert_eq!(window_offsets(0, 3, true).count(), 0);
+    }
+
+    #[test]
+    fn test_window_validity() {
+        for len in 0..8 {
+            for window_size in 1..6 {
+                for min_periods in 0..7 {
+                    for center in [false, true] {
+                        let expected = window_offsets(len, window_size, center)
+                            .map(|(start, end)| end - start >= min_periods)
+                            .collect::<Vec<_>>();
+                        let (head, tail) =
+                            window_invalid_lengths(len, window_size, min_periods, center);
+                        assert_eq!(
+                            head + tail,
+                            expected.iter().filter(|valid| !**valid).count()
+                        );
+                        let validity = window_validity(len, window_size, min_periods, center)
+                            .map_or_else(|| vec![true; len], |v| v.iter().collect());
+                        assert_eq!(validity, expected, "{len} {window_size} {min_periods}");
+                    }
+                }
             }
         }
-        // Tail.
-        for i in (0..len).rev() {
-            let (start, end) = det_offsets_fn(i, window_size, len);
-            if (end - start) < min_periods {
-                validity.set(i, false)
-            } else {
-                break;
+    }
+
+    #[test]
+    fn test_leading_invalid_validity() {
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 128, 130] {
+            for n_invalid in [0, 1, 7, 8, 9, 63, 64, 65, 128, 130] {
+                if n_invalid > len {
+                    continue;
+                }
+                let validity = leading_invalid_validity(len, n_invalid);
+                let expected = (0..len).map(|i| i >= n_invalid).collect::<Vec<_>>();
+                assert_eq!(validity.iter().collect::<Vec<_>>(), expected);
+                assert_eq!(validity.unset_bits(), n_invalid, "{len} {n_invalid}");
             }
         }
+    }
 
-        Some(validity)
-    } else {
-        None
+    #[test]
+    fn test_invalid_ends_validity() {
+        let len = 130;
+        for head in [0, 1, 63, 64, 65] {
+            for tail in [0, 1, 63, 64, 65] {
+                let validity: Bitmap = invalid_ends_validity(len, head, tail).into();
+                let expected = (0..len)
+                    .map(|i| i >= head && i < len - tail)
+                    .collect::<Vec<_>>();
+                assert_eq!(validity.iter().collect::<Vec<_>>(), expected);
+                assert_eq!(validity.unset_bits(), head + tail, "{head} {tail}");
+            }
+        }
     }
-}
 
-// Parameters allowed for rolling operations.
-#[derive(Clone, Copy, Debug)]
-pub struct RollingVarParams {
-    pub ddof: u8,
-}
+    #[test]
+    fn test_merge_leading_invalid_validity() {
+        let len = 130;
+        let existing = (0..len).map(|i| i % 3 != 0).collect::<Bitmap>();
+        for n_invalid in [0, 63, 64, 65] {
+            let merged = merge_leading_invalid_validity(Some(&existing), len, n_invalid).unwrap();
+            let expected = (0..len)
+                .map(|i| i >= n_invalid && i % 3 != 0)
+                .collect::<Vec<_>>();
+            assert_eq!(merged.iter().collect::<Vec<_>>(), expected, "{n_invalid}");
+        }
+        assert!(merge_leading_invalid_validity(None, len, 0).is_none());
+        assert_eq!(
+            merge_leading_invalid_validity(None, len, 64).map(|v| v.unset_bits()),
+            Some(64)
+        );
+    }
+
+    #[test]
+    fn test_kernels_agree_on_alignment() {
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        // The null kernels only run on arrays with a validity.
+        let arr = PrimitiveArray::from_vec(values.to_vec())
+            .with_validity(Some(Bitmap::new_with_value(true, values.len())));
+        // The maximum of a window is its last value.
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 1.0,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+        for window_size in 1..5 {
+            for min_periods in 1..5 {
+                for center in [false, true] {
+                    let expected = window_offsets(values.len(), window_size, center)
+                        .map(|(start, end)| (end - start >= min_periods).then_some(end as f64))
+                        .collect::<Vec<_>>();
+                    let outputs = [
+                        no_nulls::rolling_max(values, window_size, min_periods, center, None, None)
+                            .unwrap(),
+                        no_nulls::rolling_quantile(
+                            values,
+                            window_size,
+                            min_periods,
+                            center,
+                            None,
+                            pars.clone(),
+                        )
+                        .unwrap(),
+                        nulls::rolling_max(&arr, window_size, min_periods, center, None, None),
+                        nulls::rolling_quantile(
+                            &arr,
+                            window_size,
+                            min_periods,
+                            center,
+                            None,
+                            pars.clone(),
+                        ),
+                    ];
+                    for out in outputs {
+                        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                        let out = out.iter().map(|v| v.copied()).collect::<Vec<_>>();
+                        assert_eq!(out, expected, "{window_size} {min_periods} {center}");
+                    }
+                }
+            }
+        }
+    }
 
-#[derive(Clone, Copy, Debug)]
-pub struct RollingQuantileParams {
-    pub prob: f64,
-    pub interpol: QuantileInterpolOptions,
+    #[test]
+    fn test_min_max_propagate_nan() {
+        let values = &[1.0f64, f64::NAN, 3.0, 4.0, 5.0];
+        let arr = PrimitiveArray::from_vec(values.to_vec())
+            .with_validity(Some(Bitmap::new_with_value(true, values.len())));
+        let weights = &[1.0, 1.0];
+        // Every window holding the NaN is NaN, for all kernels.
+        let expected = [Some(1.0), None, None, Some(4.0), Some(5.0)];
+        let outputs = [
+            no_nulls::rolling_max(values, 2, 1, false, None, None).unwrap(),
+            no_nulls::rolling_max(values, 2, 1, false, Some(weights), None).unwrap(),
+            nulls::rolling_max(&arr, 2, 1, false, None, None),
+        ];
+        for out in outputs {
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out
+                .values_iter()
+                .map(|v| (!v.is_nan()).then_some(*v))
+                .collect::<Vec<_>>();
+            assert_eq!(out, expected);
+        }
+
+        let expected = [Some(1.0), None, None, Some(3.0), Some(4.0)];
+        let outputs = [
+            no_nulls::rolling_min(values, 2, 1, false, None, None).unwrap(),
+            no_nulls::rolling_min(values, 2, 1, false, Some(weights), None).unwrap(),
+            nulls::rolling_min(&arr, 2, 1, false, None, None),
+        ];
+        for out in outputs {
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out
+                .values_iter()
+                .map(|v| (!v.is_nan()).then_some(*v))
+                .collect::<Vec<_>>();
+            assert_eq!(out, expected);
+        }
+    }
 }
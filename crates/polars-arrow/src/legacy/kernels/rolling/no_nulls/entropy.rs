@@ -0,0 +1,74 @@
+use polars_utils::total_ord::TotalEq;
+
+use super::*;
+
+/// Rolling Shannon entropy of the value frequencies in trailing windows of
+/// `window_size` elements, in units of `base` (e.g. `2.0` for bits).
+///
+/// Equal values are adjacent in the sorted window, so the frequencies are the
+/// lengths of the runs of equal values. The first `window_size - 1` outputs are
+/// computed over the partial windows at the start of `values`.
+pub fn rolling_entropy<T>(values: &[T], window_size: usize, base: f64) -> Vec<f64>
+where
+    T: NativeType + TotalEq,
+{
+    if values.is_empty() {
+        return vec![];
+    }
+    let len = values.len();
+    let offsets = window_offsets(len, window_size, false);
+    let (start, end) = offsets.clone().next().unwrap();
+    let mut sorted = SortedBuf::new(values, start, end);
+    let ln_base = base.ln();
+
+    offsets
+        .map(|(start, end)| {
+            // safety: we are in bounds
+            let window = unsafe { sorted.update(start, end) };
+            entropy_sorted(window) / ln_base
+        })
+        .collect_trusted()
+}
+
+/// Entropy in nats of the values of a sorted window.
+fn entropy_sorted<T: NativeType + TotalEq>(window: &[T]) -> f64 {
+    let n = window.len() as f64;
+    let mut entropy = 0.0;
+    let mut run_start = 0;
+    for i in 1..=window.len() {
+        if i == window.len() || !window[i].tot_eq(&window[run_start]) {
+            let p = (i - run_start) as f64 / n;
+            entropy -= p * p.ln();
+            run_start = i;
+        }
+    }
+    entropy
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_entropy() {
+        // Every full window holds four distinct values: the maximum entropy of 2 bits.
+        let values = &[1i32, 2, 3, 4, 5, 6];
+        let out = rolling_entropy(values, 4, 2.0);
+        let expected = [0.0, 1.0, 3.0f64.log2(), 2.0, 2.0, 2.0];
+        for (o, e) in out.iter().zip(expected) {
+            assert!((o - e).abs() < 1e-12, "{o} != {e}");
+        }
+
+        // Constant windows carry no information.
+        let values = &[7.0f64; 5];
+        let out = rolling_entropy(values, 3, 2.0);
+        assert!(out.iter().all(|v| *v == 0.0));
+
+        // Two equally likely values are one bit or `ln(2)` nats.
+        let values = &[1u8, 1, 2, 2];
+        let out = rolling_entropy(values, 4, 2.0);
+        assert!((out[3] - 1.0).abs() < 1e-12);
+        let out = rolling_entropy(values, 4, std::f64::consts::E);
+        assert!((out[3] - 2.0f64.ln()).abs() < 1e-12);
+    }
+}
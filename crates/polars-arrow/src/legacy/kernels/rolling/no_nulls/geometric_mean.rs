@@ -0,0 +1,82 @@
+use num_traits::ToPrimitive;
+
+use super::*;
+
+/// Rolling geometric mean over trailing windows of `window_size` elements.
+///
+/// The logarithms are summed in sorted order, which keeps the accumulated
+/// rounding error small when the window contains values of very different
+/// magnitudes. The first `window_size - 1` outputs are computed over the
+/// partial windows at the start of `values`.
+///
+/// The geometric mean is only defined for positive data: a window that
+/// contains a value `<= 0` (or a NaN) produces `NaN`.
+pub fn rolling_geometric_mean<T>(values: &[T], window_size: usize) -> Vec<f64>
+where
+    T: NativeType + ToPrimitive,
+{
+    if values.is_empty() {
+        return vec![];
+    }
+    let len = values.len();
+    let offsets = window_offsets(len, window_size, false);
+    let (start, end) = offsets.clone().next().unwrap();
+    let mut sorted = SortedBuf::new(values, start, end);
+
+    offsets
+        .map(|(start, end)| {
+            // safety: we are in bounds
+            let window = unsafe { sorted.update(start, end) };
+            geometric_mean_sorted(window)
+        })
+        .collect_trusted()
+}
+
+fn geometric_mean_sorted<T: NativeType + ToPrimitive>(window: &[T]) -> f64 {
+    // The window is sorted, so the smallest value decides whether all values are positive.
+    match window.first().and_then(|v| v.to_f64()) {
+        Some(min) if min > 0.0 => {},
+        _ => return f64::NAN,
+    }
+    let log_sum = window
+        .iter()
+        .map(|v| v.to_f64().map_or(f64::NAN, f64::ln))
+        .sum::<f64>();
+    (log_sum / window.len() as f64).exp()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_geometric_mean() {
+        let values = &[1.0f64, 4.0, 16.0, 2.0, 8.0];
+        let out = rolling_geometric_mean(values, 2);
+        let expected = [1.0, 2.0, 8.0, 32.0f64.sqrt(), 4.0];
+        for (o, e) in out.iter().zip(expected) {
+            assert!((o - e).abs() < 1e-12, "{o} != {e}");
+        }
+
+        let values = &[2i32, 8, 4];
+        let out = rolling_geometric_mean(values, 3);
+        let expected = [2.0, 4.0, 4.0];
+        for (o, e) in out.iter().zip(expected) {
+            assert!((o - e).abs() < 1e-12, "{o} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_rolling_geometric_mean_non_positive() {
+        let values = &[1.0f64, 0.0, 4.0, 9.0];
+        let out = rolling_geometric_mean(values, 2);
+        assert_eq!(out[0], 1.0);
+        assert!(out[1].is_nan());
+        assert!(out[2].is_nan());
+        assert!((out[3] - 6.0).abs() < 1e-12);
+
+        let values = &[-1i64, 1];
+        let out = rolling_geometric_mean(values, 2);
+        assert!(out.iter().all(|v| v.is_nan()));
+    }
+}
@@ -36,21 +36,17 @@ pub fn rolling_mean<T>(
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
 {
-    let offset_fn = match center {
-        true => det_offsets_center,
-        false => det_offsets,
-    };
     match weights {
-        None => rolling_apply_agg_window::<MeanWindow<_>, _, _>(
+        None => rolling_apply_agg_window::<MeanWindow<_>, _>(
             values,
             window_size,
             min_periods,
-            offset_fn,
+            center,
             None,
         ),
         Some(weights) => {
             // A weighted mean is a weighted sum with normalized weights
-            let mut wts = no_nulls::coerce_weights(weights);
+            let mut wts = no_nulls::coerce_weights(weights)?;
             let wsum = wts.iter().fold(T::zero(), |acc, x| acc + *x);
             polars_ensure!(
                 wsum != T::zero(),
@@ -61,7 +57,7 @@ where
                 values,
                 window_size,
                 min_periods,
-                offset_fn,
+                center,
                 no_nulls::compute_sum_weights,
                 &wts,
             )
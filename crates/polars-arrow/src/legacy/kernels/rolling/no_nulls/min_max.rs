@@ -205,31 +205,26 @@ minmax_window!(MaxWindow, get_max_and_idx, new_is_max, n_sorted_past_max);
 
 pub(crate) fn compute_min_weights<T>(values: &[T], weights: &[T]) -> T
 where
-    T: NativeType + PartialOrd + std::ops::Mul<Output = T>,
+    T: NativeType + PartialOrd + IsFloat + Mul<Output = T>,
 {
     values
         .iter()
         .zip(weights)
         .map(|(v, w)| *v * *w)
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
+        .reduce(propagate_nan_min)
         .unwrap()
 }
 
 pub(crate) fn compute_max_weights<T>(values: &[T], weights: &[T]) -> T
 where
-    T: NativeType + PartialOrd + IsFloat + Bounded + Mul<Output = T>,
+    T: NativeType + PartialOrd + IsFloat + Mul<Output = T>,
 {
-    let mut max = T::min_value();
-    for v in values.iter().zip(weights).map(|(v, w)| *v * *w) {
-        if T::is_float() && v.is_nan() {
-            return v;
-        }
-        if v > max {
-            max = v
-        }
-    }
-
-    max
+    values
+        .iter()
+        .zip(weights)
+        .map(|(v, w)| *v * *w)
+        .reduce(propagate_nan_max)
+        .unwrap()
 }
 
 // Same as the window definition. The dispatch is identical up to the name.
@@ -246,16 +241,12 @@ macro_rules! rolling_minmax_func {
         where
             T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
         {
-            let offset_fn = match center {
-                true => det_offsets_center,
-                false => det_offsets,
-            };
             match weights {
-                None => rolling_apply_agg_window::<$window<_>, _, _>(
+                None => rolling_apply_agg_window::<$window<_>, _>(
                     values,
                     window_size,
                     min_periods,
-                    offset_fn,
+                    center,
                     None,
                 ),
                 Some(weights) => {
@@ -263,15 +254,12 @@ macro_rules! rolling_minmax_func {
                         T::is_float(),
                         "implementation error, should only be reachable by float types"
                     );
-                    let weights = weights
-                        .iter()
-                        .map(|v| NumCast::from(*v).unwrap())
-                        .collect::<Vec<_>>();
+                    let weights = no_nulls::coerce_weights(weights)?;
                     no_nulls::rolling_apply_weights(
                         values,
                         window_size,
                         min_periods,
-                        offset_fn,
+                        center,
                         $wtd_f,
                         &weights,
                     )
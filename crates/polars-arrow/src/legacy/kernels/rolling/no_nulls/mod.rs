@@ -1,3 +1,5 @@
+mod entropy;
+mod geometric_mean;
 mod mean;
 mod min_max;
 mod quantile;
@@ -6,12 +8,12 @@ mod variance;
 
 use std::fmt::Debug;
 
+pub use entropy::*;
+pub use geometric_mean::*;
 pub use mean::*;
 pub use min_max::*;
 use num_traits::{Float, NumCast};
 pub use quantile::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 pub use sum::*;
 pub use variance::*;
 
@@ -19,6 +21,7 @@ use super::*;
 use crate::array::PrimitiveArray;
 use crate::datatypes::ArrowDataType;
 use crate::legacy::error::{polars_bail, PolarsResult};
+pub use crate::legacy::kernels::QuantileInterpolOptions;
 use crate::legacy::utils::CustomIterTools;
 use crate::types::NativeType;
 
@@ -32,79 +35,65 @@ pub trait RollingAggWindowNoNulls<'a, T: NativeType> {
 }
 
 // Use an aggregation window that maintains the state
-pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
+pub(super) fn rolling_apply_agg_window<'a, Agg, T>(
     values: &'a [T],
     window_size: usize,
     min_periods: usize,
-    det_offsets_fn: Fo,
+    center: bool,
     params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
     Agg: RollingAggWindowNoNulls<'a, T>,
     T: Debug + NativeType,
 {
     let len = values.len();
-    let (start, end) = det_offsets_fn(0, window_size, len);
+    let offsets = window_offsets(len, window_size, center);
+    let (start, end) = offsets.clone().next().unwrap_or((0, 0));
     let mut agg_window = Agg::new(values, start, end, params);
 
-    let out = (0..len)
-        .map(|idx| {
-            let (start, end) = det_offsets_fn(idx, window_size, len);
+    let out = offsets
+        .map(|(start, end)| {
             // safety:
             // we are in bounds
             unsafe { agg_window.update(start, end) }
         })
         .collect_trusted::<Vec<_>>();
 
-    let validity = create_validity(min_periods, len, window_size, det_offsets_fn);
+    let validity = window_validity(len, window_size, min_periods, center);
     Ok(Box::new(PrimitiveArray::new(
         T::PRIMITIVE.into(),
         out.into(),
-        validity.map(|b| b.into()),
+        validity,
     )))
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum QuantileInterpolOptions {
-    #[default]
-    Nearest,
-    Lower,
-    Higher,
-    Midpoint,
-    Linear,
-}
-
-pub(super) fn rolling_apply_weights<T, Fo, Fa>(
+pub(super) fn rolling_apply_weights<T, Fa>(
     values: &[T],
     window_size: usize,
     min_periods: usize,
-    det_offsets_fn: Fo,
+    center: bool,
     aggregator: Fa,
     weights: &[T],
 ) -> PolarsResult<ArrayRef>
 where
     T: NativeType,
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
     Fa: Fn(&[T], &[T]) -> T,
 {
     assert_eq!(weights.len(), window_size);
     let len = values.len();
-    let out = (0..len)
-        .map(|idx| {
-            let (start, end) = det_offsets_fn(idx, window_size, len);
+    let out = window_offsets(len, window_size, center)
+        .map(|(start, end)| {
             let vals = unsafe { values.get_unchecked(start..end) };
 
             aggregator(vals, weights)
         })
         .collect_trusted::<Vec<T>>();
 
-    let validity = create_validity(min_periods, len, window_size, det_offsets_fn);
+    let validity = window_validity(len, window_size, min_periods, center);
     Ok(Box::new(PrimitiveArray::new(
         ArrowDataType::from(T::PRIMITIVE),
         out.into(),
-        validity.map(|b| b.into()),
+        validity,
     )))
 }
 
@@ -134,11 +123,6 @@ where
     values.iter().zip(weights).map(|(v, w)| *v * *w).sum()
 }
 
-pub(super) fn coerce_weights<T: NumCast>(weights: &[f64]) -> Vec<T>
-where
-{
-    weights
-        .iter()
-        .map(|v| NumCast::from(*v).unwrap())
-        .collect::<Vec<_>>()
+pub(super) fn coerce_weights<T: NumCast>(weights: &[f64]) -> PolarsResult<Vec<T>> {
+    weights.iter().map(|v| try_cast_num(*v)).collect()
 }
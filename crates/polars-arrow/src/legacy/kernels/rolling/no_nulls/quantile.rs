@@ -2,7 +2,9 @@ use std::fmt::Debug;
 
 use num_traits::ToPrimitive;
 use polars_error::polars_ensure;
+use polars_utils::functions::{lerp, lerp_float};
 use polars_utils::slice::GetSaferUnchecked;
+use polars_utils::total_ord::TotalEq;
 
 use super::QuantileInterpolOptions::*;
 use super::*;
@@ -11,6 +13,10 @@ pub struct QuantileWindow<'a, T: NativeType> {
     sorted: SortedBuf<'a, T>,
     prob: f64,
     interpol: QuantileInterpolOptions,
+    // the output of the previous window, reused if the window values didn't change
+    last: Option<T>,
+    // whether the output of the previous window was interpolated between two values
+    interpolated: bool,
 }
 
 impl<
@@ -34,68 +40,39 @@ impl<
             sorted: SortedBuf::new(slice, start, end),
             prob: params.prob,
             interpol: params.interpol,
+            last: None,
+            interpolated: false,
         }
     }
 
     unsafe fn update(&mut self, start: usize, end: usize) -> T {
         let vals = self.sorted.update(start, end);
-        let length = vals.len();
-
-        let idx = match self.interpol {
-            Linear => {
-                // Maybe add a fast path for median case? They could branch depending on odd/even.
-                let length_f = length as f64;
-                let idx = ((length_f - 1.0) * self.prob).floor() as usize;
-
-                let float_idx_top = (length_f - 1.0) * self.prob;
-                let top_idx = float_idx_top.ceil() as usize;
-                return if idx == top_idx {
-                    unsafe { *vals.get_unchecked_release(idx) }
-                } else {
-                    let proportion = T::from(float_idx_top - idx as f64).unwrap();
-                    let vi = unsafe { *vals.get_unchecked_release(idx) };
-                    let vj = unsafe { *vals.get_unchecked_release(top_idx) };
-
-                    proportion * (vj - vi) + vi
-                };
-            },
-            Midpoint => {
-                let length_f = length as f64;
-                let idx = (length_f * self.prob) as usize;
-                let idx = std::cmp::min(idx, length - 1);
-
-                let top_idx = ((length_f - 1.0) * self.prob).ceil() as usize;
-                return if top_idx == idx {
-                    // safety
-                    // we are in bounds
-                    unsafe { *vals.get_unchecked_release(idx) }
-                } else {
-                    // safety
-                    // we are in bounds
-                    let (mid, mid_plus_1) = unsafe {
-                        (
-                            *vals.get_unchecked_release(idx),
-                            *vals.get_unchecked_release(idx + 1),
-                        )
-                    };
-
-                    (mid + mid_plus_1) / (T::one() + T::one())
-                };
-            },
-            Nearest => {
-                let idx = ((length as f64) * self.prob) as usize;
-                std::cmp::min(idx, length - 1)
-            },
-            Lower => ((length as f64 - 1.0) * self.prob).floor() as usize,
-            Higher => {
-                let idx = ((length as f64 - 1.0) * self.prob).ceil() as usize;
-                std::cmp::min(idx, length - 1)
-            },
-        };
+        if let (true, Some(last)) = (self.sorted.is_unchanged(), self.last) {
+            return last;
+        }
+        let (idx, top_idx, proportion) = quantile_index(vals.len(), self.prob, self.interpol);
+        self.interpolated = idx != top_idx;
 
         // safety
         // we are in bounds
-        unsafe { *vals.get_unchecked_release(idx) }
+        let lower = unsafe { *vals.get_unchecked_release(idx) };
+        let out = if idx == top_idx {
+            lower
+        } else {
+            let upper = unsafe { *vals.get_unchecked_release(top_idx) };
+            lerp_float(lower, upper, proportion)
+        };
+        self.last = Some(out);
+        out
+    }
+}
+
+#[cfg(feature = "rolling_trace")]
+impl<'a, T: NativeType> QuantileWindow<'a, T> {
+    /// The number of removed and inserted values and the resulting window length of every
+    /// update of the sorted window.
+    pub fn trace(&self) -> &[(usize, usize, usize)] {
+        self.sorted.trace()
     }
 }
 
@@ -120,16 +97,12 @@ where
         + PartialOrd
         + Sub<Output = T>,
 {
-    let offset_fn = match center {
-        true => det_offsets_center,
-        false => det_offsets,
-    };
     match weights {
-        None => rolling_apply_agg_window::<QuantileWindow<_>, _, _>(
+        None => rolling_apply_agg_window::<QuantileWindow<_>, _>(
             values,
             window_size,
             min_periods,
-            offset_fn,
+            center,
             params,
         ),
         Some(weights) => {
@@ -140,22 +113,331 @@ where
             );
             let params = params.unwrap();
             let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
-            Ok(rolling_apply_weighted_quantile(
+            rolling_apply_weighted_quantile(
                 values,
                 params.prob,
                 params.interpol,
                 window_size,
                 min_periods,
-                offset_fn,
+                center,
                 weights,
                 wsum,
-            ))
+            )
         },
     }
 }
 
+/// An unweighted [`rolling_quantile`] that also returns, per window, whether the quantile
+/// was interpolated between two values of the window rather than being one of them.
+///
+/// Both outputs are null for the windows that hold fewer than `min_periods` values.
+pub fn rolling_quantile_with_interpolated<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: DynArgs,
+) -> (ArrayRef, BooleanArray)
+where
+    T: NativeType
+        + Float
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + One
+        + Zero
+        + PartialOrd
+        + Sub<Output = T>,
+{
+    let len = values.len();
+    let offsets = window_offsets(len, window_size, center);
+    let (start, end) = offsets.clone().next().unwrap_or((0, 0));
+    let mut agg_window = QuantileWindow::new(values, start, end, params);
+    let mut interpolated = MutableBitmap::with_capacity(len);
+    let out = offsets
+        .map(|(start, end)| {
+            // safety:
+            // we are in bounds
+            let out = unsafe { agg_window.update(start, end) };
+            interpolated.push(agg_window.interpolated);
+            out
+        })
+        .collect_trusted::<Vec<_>>();
+
+    let validity = window_validity(len, window_size, min_periods, center);
+    let interpolated = BooleanArray::new(
+        ArrowDataType::Boolean,
+        interpolated.into(),
+        validity.clone(),
+    );
+    let out = PrimitiveArray::new(T::PRIMITIVE.into(), out.into(), validity);
+    (Box::new(out), interpolated)
+}
+
+/// An unweighted [`rolling_quantile`] over trailing windows, or over leading windows if `reverse`
+/// is set.
+///
+/// With `reverse`, the window of output `i` holds the values `i..i + window_size`, so it looks
+/// forward in time. This equals reversing the values, computing a trailing rolling quantile and
+/// reversing the output, without allocating the reversed buffers.
+pub fn rolling_quantile_directed<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    reverse: bool,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType
+        + Float
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + One
+        + Zero
+        + PartialOrd
+        + Sub<Output = T>,
+{
+    if !reverse {
+        return rolling_quantile(values, window_size, min_periods, false, None, params);
+    }
+    let len = values.len();
+    let offsets = (0..len).map(|i| (i, std::cmp::min(len, i + window_size)));
+    let (start, end) = offsets.clone().next().unwrap_or((0, 0));
+    let mut agg_window = QuantileWindow::new(values, start, end, params);
+    let out = offsets
+        .map(|(start, end)| {
+            // safety:
+            // we are in bounds
+            unsafe { agg_window.update(start, end) }
+        })
+        .collect_trusted::<Vec<_>>();
+
+    // Leading windows are the trailing windows of the reversed values, so the nulls that
+    // trailing windows have at the start are at the end here.
+    let validity = (min_periods > 1).then(|| {
+        let (head, _) = window_invalid_lengths(len, window_size, min_periods, false);
+        invalid_ends_validity(len, 0, head).into()
+    });
+    Ok(Box::new(PrimitiveArray::new(
+        T::PRIMITIVE.into(),
+        out.into(),
+        validity,
+    )))
+}
+
+/// An unweighted [`rolling_quantile`] over trailing windows that pushes its output into `out`
+/// instead of allocating a new array. The windows that hold fewer than `min_periods` values
+/// are pushed as nulls.
+pub fn rolling_quantile_into_array<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    params: DynArgs,
+    out: &mut MutablePrimitiveArray<T>,
+) where
+    T: NativeType
+        + Float
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + One
+        + Zero
+        + PartialOrd
+        + Sub<Output = T>,
+{
+    let len = values.len();
+    out.reserve(len);
+    let (head, _) = window_invalid_lengths(len, window_size, min_periods, false);
+    if head > 0 {
+        out.extend_constant(head, None);
+    }
+    let mut offsets = window_offsets(len, window_size, false)
+        .skip(head)
+        .peekable();
+    let Some(&(start, end)) = offsets.peek() else {
+        return;
+    };
+    let mut agg_window = QuantileWindow::new(values, start, end, params);
+    for (start, end) in offsets {
+        // safety:
+        // we are in bounds
+        out.push(Some(unsafe { agg_window.update(start, end) }));
+    }
+}
+
+/// A rolling quantile that selects a value of the window, so that it never has to
+/// convert the values to floats. Only supports `Lower`, `Higher` and `Nearest`.
+pub struct QuantileSelectWindow<'a, T: NativeType> {
+    sorted: SortedBuf<'a, T>,
+    prob: f64,
+    interpol: QuantileInterpolOptions,
+}
+
+impl<'a, T: NativeType> RollingAggWindowNoNulls<'a, T> for QuantileSelectWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
+        let params = params.unwrap();
+        let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
+        Self {
+            sorted: SortedBuf::new(slice, start, end),
+            prob: params.prob,
+            interpol: params.interpol,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        let vals = self.sorted.update(start, end);
+        let idx = quantile_select_idx(vals.len(), self.prob, self.interpol).unwrap();
+        // safety
+        // we are in bounds
+        unsafe { *vals.get_unchecked_release(idx) }
+    }
+}
+
+/// Rolling quantile with `Lower`, `Higher` or `Nearest` interpolation that keeps the values
+/// in `T`. This works for integers without a round-trip through `f64`, so the output values
+/// are exactly the input values.
+pub fn rolling_quantile_select<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType,
+{
+    polars_ensure!(
+        weights.is_none(),
+        ComputeError: "weights are not supported for a selecting rolling quantile"
+    );
+    let interpol = params
+        .as_ref()
+        .and_then(|params| params.downcast_ref::<RollingQuantileParams>())
+        .map(|params| params.interpol);
+    polars_ensure!(
+        matches!(interpol, Some(Lower | Higher | Nearest)),
+        ComputeError: "a selecting rolling quantile needs 'lower', 'higher' or 'nearest' interpolation"
+    );
+    rolling_apply_agg_window::<QuantileSelectWindow<_>, _>(
+        values,
+        window_size,
+        min_periods,
+        center,
+        params,
+    )
+}
+
+/// A rolling quantile on `i128` values, e.g. the physical values of decimals, that
+/// interpolates in 128-bit arithmetic and keeps the output in `i128`.
+pub struct QuantileI128Window<'a> {
+    sorted: SortedBuf<'a, i128>,
+    prob: f64,
+    interpol: QuantileInterpolOptions,
+}
+
+impl<'a> RollingAggWindowNoNulls<'a, i128> for QuantileI128Window<'a> {
+    fn new(slice: &'a [i128], start: usize, end: usize, params: DynArgs) -> Self {
+        let params = params.unwrap();
+        let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
+        Self {
+            sorted: SortedBuf::new(slice, start, end),
+            prob: params.prob,
+            interpol: params.interpol,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> i128 {
+        let vals = self.sorted.update(start, end);
+        let (idx, top_idx, proportion) = quantile_index(vals.len(), self.prob, self.interpol);
+
+        // safety
+        // we are in bounds
+        let lower = unsafe { *vals.get_unchecked_release(idx) };
+        if idx == top_idx {
+            lower
+        } else {
+            let upper = unsafe { *vals.get_unchecked_release(top_idx) };
+            lerp(lower, upper, proportion)
+        }
+    }
+}
+
+/// Rolling quantile on `i128` values with any interpolation.
+///
+/// Unlike [`rolling_quantile`], the values are never converted to floats, so values beyond the
+/// precision of `f64` keep their exact value. An interpolated quantile is rounded half to even,
+/// see [`lerp`](polars_utils::functions::lerp).
+pub fn rolling_quantile_i128(
+    values: &[i128],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef> {
+    polars_ensure!(
+        weights.is_none(),
+        ComputeError: "weights are not supported for an i128 rolling quantile"
+    );
+    rolling_apply_agg_window::<QuantileI128Window, _>(
+        values,
+        window_size,
+        min_periods,
+        center,
+        params,
+    )
+}
+
+/// The dense rank of the quantile of every trailing window of `k` values.
+///
+/// The quantile is the value that [`rolling_quantile_select`] selects with `Lower` interpolation.
+/// Its dense rank is its 1-based rank among the distinct values of the window, so equal values
+/// share a rank. This suits ordinal data, e.g. the physical values of ordered categories, where
+/// the rank means more than the value. The first windows hold fewer than `k` values.
+///
+/// # Panics
+/// Panics if `k` is zero.
+pub fn rolling_quantile_dense_rank<T: NativeType>(
+    k: usize,
+    slice: &[T],
+    quantile: f64,
+) -> Vec<u32> {
+    assert!(k > 0, "the window size must be positive");
+    let offsets = window_offsets(slice.len(), k, false);
+    let (start, end) = offsets.clone().next().unwrap_or((0, 0));
+    let mut sorted = SortedBuf::new(slice, start, end);
+    offsets
+        .map(|(start, end)| {
+            // safety:
+            // we are in bounds
+            let vals = unsafe { sorted.update(start, end) };
+            let idx = quantile_index(vals.len(), quantile, Lower).0;
+            // The sorted window is a sequence of runs of equal values, count the runs up to the
+            // quantile.
+            let runs = vals[..=idx]
+                .windows(2)
+                .filter(|w| !w[0].tot_eq(&w[1]))
+                .count();
+            runs as u32 + 1
+        })
+        .collect()
+}
+
 #[inline]
-fn compute_wq<T>(buf: &[(T, f64)], p: f64, wsum: f64, interp: QuantileInterpolOptions) -> T
+fn compute_wq<T>(
+    buf: &[(T, f64)],
+    p: f64,
+    wsum: f64,
+    interp: QuantileInterpolOptions,
+) -> PolarsResult<T>
 where
     T: Debug + NativeType + Mul<Output = T> + Sub<Output = T> + NumCast + ToPrimitive + Zero,
 {
@@ -174,7 +456,7 @@ where
         (s_old, v_old, vk) = (s, vk, v);
         s += w;
     }
-    match (h == s_old, interp) {
+    let out = match (h == s_old, interp) {
         (true, _) => v_old, // If we hit the break exactly interpolation shouldn't matter
         (_, Lower) => v_old,
         (_, Higher) => vk,
@@ -185,27 +467,25 @@ where
                 vk
             }
         },
-        (_, Midpoint) => (vk + v_old) * NumCast::from(0.5).unwrap(),
+        (_, Midpoint) => (vk + v_old) * try_cast_num(0.5)?,
         // This is seemingly the canonical way to do it.
-        (_, Linear) => {
-            v_old + <T as NumCast>::from((h - s_old) / (s - s_old)).unwrap() * (vk - v_old)
-        },
-    }
+        (_, Linear) => v_old + try_cast_num::<T, _>((h - s_old) / (s - s_old))? * (vk - v_old),
+    };
+    Ok(out)
 }
 
 #[allow(clippy::too_many_arguments)]
-fn rolling_apply_weighted_quantile<T, Fo>(
+fn rolling_apply_weighted_quantile<T>(
     values: &[T],
     p: f64,
     interpolation: QuantileInterpolOptions,
     window_size: usize,
     min_periods: usize,
-    det_offsets_fn: Fo,
+    center: bool,
     weights: &[f64],
     wsum: f64,
-) -> ArrayRef
+) -> PolarsResult<ArrayRef>
 where
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
     T: Debug + NativeType + Mul<Output = T> + Sub<Output = T> + NumCast + ToPrimitive + Zero,
 {
     assert_eq!(weights.len(), window_size);
@@ -213,10 +493,9 @@ where
     let nz_idx_wts: Vec<_> = weights.iter().enumerate().filter(|x| x.1 != &0.0).collect();
     let mut buf = vec![(T::zero(), 0.0); nz_idx_wts.len()];
     let len = values.len();
-    let out = (0..len)
-        .map(|idx| {
+    let out = window_offsets(len, window_size, center)
+        .map(|(start, _)| {
             // Don't need end. Window size is constant and we computed offsets from start above.
-            let (start, _) = det_offsets_fn(idx, window_size, len);
 
             // Sorting is not ideal, see https://github.com/tobiasschoch/wquantile for something faster
             unsafe {
@@ -227,21 +506,106 @@ where
             buf.sort_unstable_by(|&a, &b| a.0.tot_cmp(&b.0));
             compute_wq(&buf, p, wsum, interpolation)
         })
-        .collect_trusted::<Vec<T>>();
+        .collect::<PolarsResult<Vec<T>>>()?;
 
-    let validity = create_validity(min_periods, len, window_size, det_offsets_fn);
-    Box::new(PrimitiveArray::new(
+    let validity = window_validity(len, window_size, min_periods, center);
+    Ok(Box::new(PrimitiveArray::new(
         T::PRIMITIVE.into(),
         out.into(),
-        validity.map(|b| b.into()),
-    ))
+        validity,
+    )))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::Array;
     use crate::legacy::kernels::rolling::no_nulls::{rolling_max, rolling_min};
 
+    #[test]
+    fn test_rolling_quantile_with_interpolated() {
+        let values = &[4.0, 1.0, 3.0, 2.0, 6.0, 5.0, 8.0, 7.0];
+        let med_pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+        for window_size in 1..6 {
+            let (out, interpolated) = rolling_quantile_with_interpolated(
+                values,
+                window_size,
+                window_size,
+                false,
+                med_pars.clone(),
+            );
+            let expected = rolling_quantile(
+                values,
+                window_size,
+                window_size,
+                false,
+                None,
+                med_pars.clone(),
+            )
+            .unwrap();
+            assert_eq!(out.as_ref(), expected.as_ref());
+            // The median of an odd number of values is the middle value, that of an even
+            // number of values is interpolated between the two middle values.
+            let expected = (0..values.len())
+                .map(|i| (i + 1 >= window_size).then_some(window_size % 2 == 0))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                interpolated.iter().collect::<Vec<_>>(),
+                expected,
+                "{window_size}"
+            );
+        }
+
+        // Lower never interpolates.
+        let lower_pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Lower,
+        }) as Arc<dyn Any + Send + Sync>);
+        let (_, interpolated) = rolling_quantile_with_interpolated(values, 4, 1, true, lower_pars);
+        assert!(interpolated.iter().all(|v| v == Some(false)));
+    }
+
+    #[test]
+    fn test_rolling_quantile_into_array() {
+        let values = &[3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.25,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+        for window_size in 1..5 {
+            for min_periods in 1..=window_size {
+                let expected =
+                    rolling_quantile(values, window_size, min_periods, false, None, pars.clone())
+                        .unwrap();
+                // Start from a non-empty array to check that the output is appended.
+                let mut out = MutablePrimitiveArray::<f64>::new();
+                out.push(Some(-1.0));
+                rolling_quantile_into_array(
+                    values,
+                    window_size,
+                    min_periods,
+                    pars.clone(),
+                    &mut out,
+                );
+                let out: PrimitiveArray<f64> = out.into();
+                assert_eq!(out.len(), values.len() + 1);
+                assert_eq!(
+                    &out.sliced(1, values.len()) as &dyn Array,
+                    expected.as_ref(),
+                    "{window_size} {min_periods}"
+                );
+            }
+        }
+
+        let mut out = MutablePrimitiveArray::<f64>::new();
+        rolling_quantile_into_array(&values[..2], 3, 3, pars, &mut out);
+        let out: PrimitiveArray<f64> = out.into();
+        assert_eq!(out.null_count(), 2);
+    }
+
     #[test]
     fn test_rolling_median() {
         let values = &[1.0, 2.0, 3.0, 4.0];
@@ -275,6 +639,60 @@ mod test {
         assert_eq!(out, &[None, None, Some(2.5), None]);
     }
 
+    #[test]
+    fn test_rolling_quantile_reverse() {
+        let values = &[3.0f64, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+        let mut reversed = values.to_vec();
+        reversed.reverse();
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.3,
+            interpol: Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        for window_size in 1..5 {
+            for min_periods in 1..=window_size {
+                let out =
+                    rolling_quantile_directed(values, window_size, min_periods, true, pars.clone())
+                        .unwrap();
+                let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+
+                let expected = rolling_quantile(
+                    &reversed,
+                    window_size,
+                    min_periods,
+                    false,
+                    None,
+                    pars.clone(),
+                )
+                .unwrap();
+                let expected = expected
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f64>>()
+                    .unwrap();
+                let mut expected = expected.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+                expected.reverse();
+                assert_eq!(out, expected, "{window_size} {min_periods}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_quantile_dense_rank() {
+        // Ordinal levels, e.g. "low" < "mid" < "high".
+        let values = &[0u8, 2, 2, 1, 1, 1, 2, 0];
+        assert_eq!(
+            rolling_quantile_dense_rank(3, values, 0.5),
+            &[1, 1, 2, 2, 1, 1, 1, 2]
+        );
+        assert_eq!(
+            rolling_quantile_dense_rank(3, values, 1.0),
+            &[1, 2, 2, 2, 2, 1, 2, 3]
+        );
+        assert_eq!(rolling_quantile_dense_rank(1, values, 0.5), &[1; 8]);
+        assert!(rolling_quantile_dense_rank::<u8>(2, &[], 0.5).is_empty());
+    }
+
     #[test]
     fn test_rolling_quantile_limits() {
         let values = &[1.0f64, 2.0, 3.0, 4.0];
@@ -313,4 +731,152 @@ mod test {
             assert_eq!(out1, out2);
         }
     }
+
+    #[test]
+    fn test_rolling_quantile_linear_proportion() {
+        // Interpolating outside of the two neighbouring values trips the debug assertion
+        // and would give values outside of the window.
+        let values = (0..30).map(|v| ((v * 7) % 11) as f64).collect::<Vec<_>>();
+        for window_size in 1..=12 {
+            for prob in (0..=20).map(|p| p as f64 / 20.0) {
+                let pars = Some(Arc::new(RollingQuantileParams {
+                    prob,
+                    interpol: Linear,
+                }) as Arc<dyn Any + Send + Sync>);
+                let out = rolling_quantile(&values, window_size, 1, false, None, pars).unwrap();
+                let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                for (i, v) in out.values_iter().enumerate() {
+                    let window = &values[(i + 1).saturating_sub(window_size)..i + 1];
+                    let min = window.iter().copied().fold(f64::INFINITY, f64::min);
+                    let max = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    assert!(min <= *v && *v <= max, "{window:?} {prob} {v}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_quantile_step_wise() {
+        // Many windows have the same value entering and leaving, their output is reused.
+        let values = &[
+            1.0f64, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 5.0, 5.0, 5.0, 5.0, 1.0, 1.0,
+        ];
+        for center in [false, true] {
+            let pars = Some(Arc::new(RollingQuantileParams {
+                prob: 0.25,
+                interpol: Linear,
+            }) as Arc<dyn Any + Send + Sync>);
+            let out = rolling_quantile(values, 3, 1, center, None, pars.clone()).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let offsets = window_offsets(values.len(), 3, center);
+            for (v, (start, end)) in out.values_iter().zip(offsets) {
+                let mut fresh = QuantileWindow::new(values, start, end, pars.clone());
+                let expected = unsafe { fresh.update(start, end) };
+                assert_eq!(*v, expected, "window {start}..{end}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_quantile_exact_index() {
+        // (26 - 1) * 0.28 is 7.000000000000001 in floating point, but lands on index 7.
+        let values = (0..26).map(|v| v as f64 * 1e16).collect::<Vec<_>>();
+        for interpol in [Lower, Higher, Midpoint, Linear] {
+            let pars = Some(Arc::new(RollingQuantileParams {
+                prob: 0.28,
+                interpol,
+            }) as Arc<dyn Any + Send + Sync>);
+            let out = rolling_quantile(&values, 26, 26, false, None, pars).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            assert_eq!(out.get(25), Some(7e16), "{interpol:?}");
+        }
+    }
+
+    #[test]
+    fn test_rolling_quantile_select_integers() {
+        // These values can't be represented exactly as f64, so a float round-trip would
+        // change them.
+        let big = i64::MAX - 1;
+        let values = &[big, 3, big - 2, -5, big - 4];
+
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: Nearest,
+        }) as Arc<dyn Any + Send + Sync>);
+        // `i64` is not `Float`, so this can only compile against the integer path.
+        let out = rolling_quantile_select::<i64>(values, 3, 1, false, None, pars).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(big), Some(big), Some(big - 2), Some(3), Some(big - 4)]
+        );
+
+        for (interpol, expected) in [(Lower, 3), (Higher, big - 2)] {
+            let pars = Some(Arc::new(RollingQuantileParams {
+                prob: 0.5,
+                interpol,
+            }) as Arc<dyn Any + Send + Sync>);
+            let out = rolling_quantile_select(values, 4, 4, false, None, pars).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+            assert_eq!(out.get(3), Some(expected), "{interpol:?}");
+        }
+
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+        assert!(rolling_quantile_select(values, 3, 1, false, None, pars).is_err());
+    }
+
+    #[test]
+    fn test_rolling_quantile_i128() {
+        let max = i128::MAX;
+        let values = &[max, max - 3, i128::MIN, max - 10, max - 1];
+
+        let quantile = |interpol, window_size| {
+            let pars = Some(Arc::new(RollingQuantileParams {
+                prob: 0.5,
+                interpol,
+            }) as Arc<dyn Any + Send + Sync>);
+            let out = rolling_quantile_i128(values, window_size, 1, false, None, pars).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+            out.into_iter().map(|v| v.copied()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            quantile(Midpoint, 2),
+            &[Some(max), Some(max - 1), Some(-2), Some(-6), Some(max - 6)]
+        );
+        assert_eq!(
+            quantile(Linear, 4),
+            &[
+                Some(max),
+                Some(max - 1),
+                Some(max - 3),
+                Some(max - 6),
+                Some(max - 6)
+            ]
+        );
+        assert_eq!(
+            quantile(Higher, 3),
+            &[
+                Some(max),
+                Some(max),
+                Some(max - 3),
+                Some(max - 10),
+                Some(max - 10)
+            ]
+        );
+
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.25,
+            interpol: Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+        let out = rolling_quantile_i128(&[i128::MIN, max], 2, 2, false, None, pars).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+        // A quarter of the way from `MIN` to `MAX`, where the span is rounded to `f64`.
+        let expected = i128::MIN + (u128::MAX as f64 * 0.25) as i128;
+        assert_eq!(out.get(1), Some(expected));
+    }
 }
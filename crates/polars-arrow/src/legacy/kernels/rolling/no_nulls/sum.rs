@@ -78,39 +78,21 @@ pub fn rolling_sum<T>(
 where
     T: NativeType + std::iter::Sum + NumCast + Mul<Output = T> + AddAssign + SubAssign + IsFloat,
 {
-    match (center, weights) {
-        (true, None) => rolling_apply_agg_window::<SumWindow<_>, _, _>(
+    match weights {
+        None => rolling_apply_agg_window::<SumWindow<_>, _>(
             values,
             window_size,
             min_periods,
-            det_offsets_center,
+            center,
             None,
         ),
-        (false, None) => rolling_apply_agg_window::<SumWindow<_>, _, _>(
-            values,
-            window_size,
-            min_periods,
-            det_offsets,
-            None,
-        ),
-        (true, Some(weights)) => {
-            let weights = no_nulls::coerce_weights(weights);
-            no_nulls::rolling_apply_weights(
-                values,
-                window_size,
-                min_periods,
-                det_offsets_center,
-                no_nulls::compute_sum_weights,
-                &weights,
-            )
-        },
-        (false, Some(weights)) => {
-            let weights = no_nulls::coerce_weights(weights);
+        Some(weights) => {
+            let weights = no_nulls::coerce_weights(weights)?;
             no_nulls::rolling_apply_weights(
                 values,
                 window_size,
                 min_periods,
-                det_offsets,
+                center,
                 no_nulls::compute_sum_weights,
                 &weights,
             )
@@ -155,22 +155,18 @@ where
         + Zero
         + Sub<Output = T>,
 {
-    let offset_fn = match center {
-        true => det_offsets_center,
-        false => det_offsets,
-    };
     match weights {
-        None => rolling_apply_agg_window::<VarWindow<_>, _, _>(
+        None => rolling_apply_agg_window::<VarWindow<_>, _>(
             values,
             window_size,
             min_periods,
-            offset_fn,
+            center,
             params,
         ),
         Some(weights) => {
             // Validate and standardize the weights like we do for the mean. This definition is fine
             // because frequency weights and unbiasing don't make sense for rolling operations.
-            let mut wts = no_nulls::coerce_weights(weights);
+            let mut wts = no_nulls::coerce_weights(weights)?;
             let wsum = wts.iter().fold(T::zero(), |acc, x| acc + *x);
             polars_ensure!(
                 wsum != T::zero(),
@@ -181,7 +177,7 @@ where
                 values,
                 window_size,
                 min_periods,
-                offset_fn,
+                center,
                 compute_var_weights,
                 &wts,
             )
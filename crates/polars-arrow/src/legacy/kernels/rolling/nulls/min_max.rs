@@ -296,10 +296,6 @@ pub struct MinWindow<'a, T: NativeType + PartialOrd + IsFloat> {
     inner: MinMaxWindow<'a, T>,
 }
 
-fn take_min<T: NativeType + IsFloat + PartialOrd>(a: T, b: T) -> T {
-    std::cmp::min_by(a, b, compare_fn_nan_min)
-}
-
 impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for MinWindow<'a, T> {
     unsafe fn new(
         slice: &'a [T],
@@ -315,7 +311,7 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for
                 start,
                 end,
                 compare_fn_nan_min,
-                take_min,
+                propagate_nan_min,
                 Ordering::Less,
             ),
         }
@@ -344,35 +340,20 @@ where
     if weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
-    if center {
-        rolling_apply_agg_window::<MinWindow<_>, _, _>(
-            arr.values().as_slice(),
-            arr.validity().as_ref().unwrap(),
-            window_size,
-            min_periods,
-            det_offsets_center,
-            None,
-        )
-    } else {
-        rolling_apply_agg_window::<MinWindow<_>, _, _>(
-            arr.values().as_slice(),
-            arr.validity().as_ref().unwrap(),
-            window_size,
-            min_periods,
-            det_offsets,
-            None,
-        )
-    }
+    rolling_apply_agg_window::<MinWindow<_>, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        center,
+        None,
+    )
 }
 
 pub struct MaxWindow<'a, T: NativeType + PartialOrd + IsFloat> {
     inner: MinMaxWindow<'a, T>,
 }
 
-fn take_max<T: NativeType + IsFloat + PartialOrd>(a: T, b: T) -> T {
-    std::cmp::max_by(a, b, compare_fn_nan_max)
-}
-
 impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for MaxWindow<'a, T> {
     unsafe fn new(
         slice: &'a [T],
@@ -388,7 +369,7 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for
                 start,
                 end,
                 compare_fn_nan_max,
-                take_max,
+                propagate_nan_max,
                 Ordering::Greater,
             ),
         }
@@ -417,43 +398,22 @@ where
     if weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
-    if center {
-        if is_reverse_sorted_max_nulls(arr.values().as_slice(), arr.validity().as_ref().unwrap()) {
-            rolling_apply_agg_window::<SortedMinMax<_>, _, _>(
-                arr.values().as_slice(),
-                arr.validity().as_ref().unwrap(),
-                window_size,
-                min_periods,
-                det_offsets_center,
-                None,
-            )
-        } else {
-            rolling_apply_agg_window::<MaxWindow<_>, _, _>(
-                arr.values().as_slice(),
-                arr.validity().as_ref().unwrap(),
-                window_size,
-                min_periods,
-                det_offsets_center,
-                None,
-            )
-        }
-    } else if is_reverse_sorted_max_nulls(arr.values().as_slice(), arr.validity().as_ref().unwrap())
-    {
-        rolling_apply_agg_window::<SortedMinMax<_>, _, _>(
+    if is_reverse_sorted_max_nulls(arr.values().as_slice(), arr.validity().as_ref().unwrap()) {
+        rolling_apply_agg_window::<SortedMinMax<_>, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
-            det_offsets,
+            center,
             None,
         )
     } else {
-        rolling_apply_agg_window::<MaxWindow<_>, _, _>(
+        rolling_apply_agg_window::<MaxWindow<_>, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
-            det_offsets,
+            center,
             None,
         )
     }
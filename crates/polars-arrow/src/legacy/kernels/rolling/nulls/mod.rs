@@ -31,37 +31,61 @@ pub trait RollingAggWindowNulls<'a, T: NativeType> {
 }
 
 // Use an aggregation window that maintains the state
-pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
+pub(super) fn rolling_apply_agg_window<'a, Agg, T>(
     values: &'a [T],
     validity: &'a Bitmap,
     window_size: usize,
     min_periods: usize,
-    det_offsets_fn: Fo,
+    center: bool,
     params: DynArgs,
 ) -> ArrayRef
 where
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End) + Copy,
     Agg: RollingAggWindowNulls<'a, T>,
     T: IsFloat + NativeType,
+{
+    rolling_apply_agg_window_inspect::<Agg, _, _>(
+        values,
+        validity,
+        window_size,
+        min_periods,
+        center,
+        params,
+        |_| {},
+    )
+}
+
+// Like `rolling_apply_agg_window`, but calls `inspect` with the state of the aggregation
+// window after every update.
+pub(super) fn rolling_apply_agg_window_inspect<'a, Agg, T, F>(
+    values: &'a [T],
+    validity: &'a Bitmap,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: DynArgs,
+    mut inspect: F,
+) -> ArrayRef
+where
+    Agg: RollingAggWindowNulls<'a, T>,
+    T: IsFloat + NativeType,
+    F: FnMut(&Agg),
 {
     let len = values.len();
-    let (start, end) = det_offsets_fn(0, window_size, len);
+    let offsets = window_offsets(len, window_size, center);
+    let (start, end) = offsets.clone().next().unwrap_or((0, 0));
     // Safety; we are in bounds
     let mut agg_window = unsafe { Agg::new(values, validity, start, end, params) };
 
-    let mut validity = create_validity(min_periods, len, window_size, det_offsets_fn)
-        .unwrap_or_else(|| {
-            let mut validity = MutableBitmap::with_capacity(len);
-            validity.extend_constant(len, true);
-            validity
-        });
+    let mut validity = create_validity(min_periods, len, window_size, center)
+        .unwrap_or_else(|| invalid_ends_validity(len, 0, 0));
 
-    let out = (0..len)
-        .map(|idx| {
-            let (start, end) = det_offsets_fn(idx, window_size, len);
+    let out = offsets
+        .enumerate()
+        .map(|(idx, (start, end))| {
             // safety:
             // we are in bounds
             let agg = unsafe { agg_window.update(start, end) };
+            inspect(&agg_window);
             match agg {
                 Some(val) => {
                     if agg_window.is_valid(min_periods) {
@@ -255,12 +279,12 @@ mod test {
 
         let arr = Int32Array::new(ArrowDataType::Int32, vals.into(), Some(validity.into()));
 
-        let out = rolling_apply_agg_window::<MaxWindow<_>, _, _>(
+        let out = rolling_apply_agg_window::<MaxWindow<_>, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
             min_periods,
-            det_offsets,
+            false,
             None,
         );
         let arr = out.as_any().downcast_ref::<Int32Array>().unwrap();
@@ -1,3 +1,4 @@
+use polars_utils::functions::lerp_float;
 use polars_utils::slice::GetSaferUnchecked;
 
 use super::*;
@@ -6,6 +7,8 @@ pub struct QuantileWindow<'a, T: NativeType + IsFloat + PartialOrd> {
     sorted: SortedBufNulls<'a, T>,
     prob: f64,
     interpol: QuantileInterpolOptions,
+    // The number of nulls in the window of the last update.
+    null_count: usize,
 }
 
 impl<
@@ -37,58 +40,28 @@ impl<
             sorted: SortedBufNulls::new(slice, validity, start, end),
             prob: params.prob,
             interpol: params.interpol,
+            null_count: 0,
         }
     }
 
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
         let (values, null_count) = self.sorted.update(start, end);
+        self.null_count = null_count;
         // The min periods_issue will be taken care of when actually rolling
         if null_count == values.len() {
             return None;
         }
         // Nulls are guaranteed to be at the front
         let values = &values[null_count..];
-        let length = values.len();
-
-        let mut idx = match self.interpol {
-            QuantileInterpolOptions::Nearest => ((length as f64) * self.prob) as usize,
-            QuantileInterpolOptions::Lower
-            | QuantileInterpolOptions::Midpoint
-            | QuantileInterpolOptions::Linear => {
-                ((length as f64 - 1.0) * self.prob).floor() as usize
-            },
-            QuantileInterpolOptions::Higher => ((length as f64 - 1.0) * self.prob).ceil() as usize,
-        };
-
-        idx = std::cmp::min(idx, length - 1);
+        let (idx, top_idx, proportion) = quantile_index(values.len(), self.prob, self.interpol);
 
         // we can unwrap because we sliced of the nulls
-        match self.interpol {
-            QuantileInterpolOptions::Midpoint => {
-                let top_idx = ((length as f64 - 1.0) * self.prob).ceil() as usize;
-                Some(
-                    (values.get_unchecked_release(idx).unwrap()
-                        + values.get_unchecked_release(top_idx).unwrap())
-                        / T::from::<f64>(2.0f64).unwrap(),
-                )
-            },
-            QuantileInterpolOptions::Linear => {
-                let float_idx = (length as f64 - 1.0) * self.prob;
-                let top_idx = f64::ceil(float_idx) as usize;
-
-                if top_idx == idx {
-                    Some(values.get_unchecked_release(idx).unwrap())
-                } else {
-                    let proportion = T::from(float_idx - idx as f64).unwrap();
-                    Some(
-                        proportion
-                            * (values.get_unchecked_release(top_idx).unwrap()
-                                - values.get_unchecked_release(idx).unwrap())
-                            + values.get_unchecked_release(idx).unwrap(),
-                    )
-                }
-            },
-            _ => Some(values.get_unchecked_release(idx).unwrap()),
+        let lower = values.get_unchecked_release(idx).unwrap();
+        if idx == top_idx {
+            Some(lower)
+        } else {
+            let upper = values.get_unchecked_release(top_idx).unwrap();
+            Some(lerp_float(lower, upper, proportion))
         }
     }
 
@@ -97,6 +70,15 @@ impl<
     }
 }
 
+#[cfg(feature = "rolling_trace")]
+impl<'a, T: NativeType + IsFloat + PartialOrd> QuantileWindow<'a, T> {
+    /// The number of removed and inserted values and the resulting window length of every
+    /// update of the sorted window.
+    pub fn trace(&self) -> &[(usize, usize, usize)] {
+        self.sorted.trace()
+    }
+}
+
 pub fn rolling_quantile<T>(
     arr: &PrimitiveArray<T>,
     window_size: usize,
@@ -122,16 +104,115 @@ where
     if weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
-    let offset_fn = match center {
-        true => det_offsets_center,
-        false => det_offsets,
-    };
-    rolling_apply_agg_window::<QuantileWindow<_>, _, _>(
+    rolling_apply_agg_window::<QuantileWindow<_>, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        center,
+        params,
+    )
+}
+
+/// Rolling quantile that skips nulls, like [`rolling_quantile`], that also returns the number
+/// of nulls in every window.
+pub fn rolling_quantile_with_null_counts<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: DynArgs,
+) -> (ArrayRef, Vec<u32>)
+where
+    T: NativeType
+        + IsFloat
+        + Float
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + One
+        + Zero
+        + PartialOrd
+        + Sub<Output = T>,
+{
+    let mut null_counts = Vec::with_capacity(arr.len());
+    let out = rolling_apply_agg_window_inspect::<QuantileWindow<_>, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        center,
+        params,
+        |window| null_counts.push(window.null_count as u32),
+    );
+    (out, null_counts)
+}
+
+/// A rolling quantile that selects a value of the window, so that it never has to
+/// convert the values to floats. Only supports `Lower`, `Higher` and `Nearest`.
+pub struct QuantileSelectWindow<'a, T: NativeType + IsFloat> {
+    sorted: SortedBufNulls<'a, T>,
+    prob: f64,
+    interpol: QuantileInterpolOptions,
+}
+
+impl<'a, T: NativeType + IsFloat> RollingAggWindowNulls<'a, T> for QuantileSelectWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: DynArgs,
+    ) -> Self {
+        let params = params.unwrap();
+        let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
+        Self {
+            sorted: SortedBufNulls::new(slice, validity, start, end),
+            prob: params.prob,
+            interpol: params.interpol,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let (values, null_count) = self.sorted.update(start, end);
+        if null_count == values.len() {
+            return None;
+        }
+        // Nulls are guaranteed to be at the front
+        let values = &values[null_count..];
+        let idx = quantile_select_idx(values.len(), self.prob, self.interpol).unwrap();
+        *values.get_unchecked_release(idx)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.sorted.is_valid(min_periods)
+    }
+}
+
+/// Rolling quantile with `Lower`, `Higher` or `Nearest` interpolation that keeps the values
+/// in `T`, see [`QuantileSelectWindow`].
+pub fn rolling_quantile_select<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: DynArgs,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    rolling_apply_agg_window::<QuantileSelectWindow<_>, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
         min_periods,
-        offset_fn,
+        center,
         params,
     )
 }
@@ -182,6 +263,37 @@ mod test {
         assert_eq!(out, &[None, None, None, None]);
     }
 
+    #[test]
+    fn test_rolling_quantile_null_counts() {
+        let validity = Bitmap::from(&[true, false, false, true, true, false, true, true]);
+        let arr = &PrimitiveArray::new(
+            ArrowDataType::Float64,
+            Buffer::from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]),
+            Some(validity.clone()),
+        );
+        let med_pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        for center in [false, true] {
+            let (out, null_counts) =
+                rolling_quantile_with_null_counts(arr, 3, 1, center, med_pars.clone());
+            let expected = window_offsets(arr.len(), 3, center)
+                .map(|(start, end)| (start..end).filter(|i| !validity.get_bit(*i)).count() as u32)
+                .collect::<Vec<_>>();
+            assert_eq!(null_counts, expected);
+
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let plain = rolling_quantile(arr, 3, 1, center, None, med_pars.clone());
+            let plain = plain
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            assert_eq!(out, plain);
+        }
+    }
+
     #[test]
     fn test_rolling_quantile_nulls_limits() {
         // compare quantiles to corresponding min/max/median values
@@ -226,4 +338,40 @@ mod test {
             assert_eq!(out1, out2);
         }
     }
+
+    #[test]
+    fn test_rolling_quantile_nulls_exact_index() {
+        let buf = Buffer::<f64>::from(vec![1.0, f64::MAX, f64::MAX, f64::MAX]);
+        let values = &PrimitiveArray::new(
+            ArrowDataType::Float64,
+            buf,
+            Some(Bitmap::from(&[false, true, true, true])),
+        );
+        // The median of three values is an element, it must not be averaged with itself.
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Midpoint,
+        }) as Arc<dyn Any + Send + Sync>);
+        let out = rolling_quantile(values, 3, 3, false, None, pars);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert_eq!(out.get(3), Some(f64::MAX));
+    }
+
+    #[test]
+    fn test_rolling_quantile_select_nulls() {
+        let big = i64::MAX - 1;
+        let values = &PrimitiveArray::new(
+            ArrowDataType::Int64,
+            Buffer::from(vec![big, 0, 3, big - 2]),
+            Some(Bitmap::from(&[true, false, true, true])),
+        );
+        let pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Higher,
+        }) as Arc<dyn Any + Send + Sync>);
+        let out = rolling_quantile_select(values, 3, 1, false, None, pars);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(big), Some(big), Some(big), Some(big - 2)]);
+    }
 }
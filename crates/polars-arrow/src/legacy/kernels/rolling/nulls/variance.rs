@@ -209,17 +209,12 @@ where
     if weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
-    let offsets_fn = if center {
-        det_offsets_center
-    } else {
-        det_offsets
-    };
-    rolling_apply_agg_window::<VarWindow<_>, _, _>(
+    rolling_apply_agg_window::<VarWindow<_>, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
         min_periods,
-        offsets_fn,
+        center,
         params,
     )
 }
@@ -1,7 +1,51 @@
+use polars_utils::slice::binary_search_total;
 use polars_utils::total_ord::TotalOrd;
 
 use super::*;
 
+/// Windows with at most this many distinct values are sorted by counting them.
+const MAX_COUNTING_SORT_DISTINCT: usize = 8;
+
+/// Sort `buf` by counting its distinct values, which is linear in the length of `buf`.
+///
+/// Returns `false` and leaves `buf` untouched if it has more than
+/// [`MAX_COUNTING_SORT_DISTINCT`] distinct values. Values are told apart by their bits, so
+/// e.g. `-0.0` and `0.0` are both kept as they are.
+pub fn counting_sort<T: NativeType>(buf: &mut [T]) -> bool {
+    let mut counts: Vec<(T, usize)> = Vec::with_capacity(MAX_COUNTING_SORT_DISTINCT);
+    for v in buf.iter() {
+        let v_bytes = bytemuck::bytes_of(v);
+        match counts
+            .iter_mut()
+            .find(|(u, _)| bytemuck::bytes_of(u) == v_bytes)
+        {
+            Some((_, count)) => *count += 1,
+            None if counts.len() < MAX_COUNTING_SORT_DISTINCT => counts.push((*v, 1)),
+            None => return false,
+        }
+    }
+    counts.sort_by(|a, b| a.0.tot_cmp(&b.0));
+
+    let mut offset = 0;
+    for (v, count) in counts {
+        buf[offset..offset + count].fill(v);
+        offset += count;
+    }
+    true
+}
+
+/// Sort a window with a counting sort as long as the windows have few distinct values,
+/// and switch to a comparison sort for good once they don't.
+fn sort_window<T: NativeType>(buf: &mut [T], low_cardinality: &mut bool) {
+    if *low_cardinality {
+        *low_cardinality = counting_sort(buf);
+        if *low_cardinality {
+            return;
+        }
+    }
+    buf.sort_by(TotalOrd::tot_cmp);
+}
+
 pub(super) struct SortedBuf<'a, T: NativeType> {
     // slice over which the window slides
     slice: &'a [T],
@@ -9,17 +53,60 @@ pub(super) struct SortedBuf<'a, T: NativeType> {
     last_end: usize,
     // values within the window that we keep sorted
     buf: Vec<T>,
+    // whether the windows so far had few enough distinct values for a counting sort
+    low_cardinality: bool,
+    // whether the last call to `update` left the values in the window unchanged
+    unchanged: bool,
+    // (removed, inserted, window length) per call to `update`
+    #[cfg(any(test, feature = "rolling_trace"))]
+    trace: Vec<(usize, usize, usize)>,
 }
 
 impl<'a, T: NativeType> SortedBuf<'a, T> {
     pub(super) fn new(slice: &'a [T], start: usize, end: usize) -> Self {
-        let mut buf = slice[start..end].to_vec();
-        buf.sort_by(TotalOrd::tot_cmp);
+        let window = &slice[start..end];
+        // Checking the order is linear, so it is cheap compared to sorting.
+        if window.windows(2).all(|w| w[0].tot_le(&w[1])) {
+            return Self::from_sorted(slice, start, end);
+        }
+        let mut buf = window.to_vec();
+        // The first window decides whether we try a counting sort.
+        let mut low_cardinality = true;
+        sort_window(&mut buf, &mut low_cardinality);
+        Self::from_sorted_buf(slice, start, end, buf, low_cardinality)
+    }
+
+    /// Create a buffer over the window `start..end` of `slice`, which the caller knows is
+    /// sorted ascending, without sorting it.
+    pub(super) fn from_sorted(slice: &'a [T], start: usize, end: usize) -> Self {
+        let buf = slice[start..end].to_vec();
+        debug_assert!(buf.windows(2).all(|w| w[0].tot_le(&w[1])));
+        // Equal values are adjacent, so the distinct values are the runs of the window.
+        let distinct = buf
+            .windows(2)
+            .filter(|w| bytemuck::bytes_of(&w[0]) != bytemuck::bytes_of(&w[1]))
+            .count()
+            + usize::from(!buf.is_empty());
+        let low_cardinality = distinct <= MAX_COUNTING_SORT_DISTINCT;
+        Self::from_sorted_buf(slice, start, end, buf, low_cardinality)
+    }
+
+    fn from_sorted_buf(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        buf: Vec<T>,
+        low_cardinality: bool,
+    ) -> Self {
         Self {
             slice,
             last_start: start,
             last_end: end,
             buf,
+            low_cardinality,
+            unchanged: false,
+            #[cfg(any(test, feature = "rolling_trace"))]
+            trace: vec![],
         }
     }
 
@@ -28,46 +115,137 @@ impl<'a, T: NativeType> SortedBuf<'a, T> {
     /// The caller must ensure that `start` and `end` are within bounds of `self.slice`
     ///
     pub(super) unsafe fn update(&mut self, start: usize, end: usize) -> &[T] {
+        // The window slides by one and the value that enters is the value that leaves, so
+        // the sorted values don't change. This is common in step-wise data.
+        self.unchanged = start == self.last_start + 1
+            && end == self.last_end + 1
+            && self.last_start < self.last_end
+            && bytemuck::bytes_of(self.slice.get_unchecked(self.last_start))
+                == bytemuck::bytes_of(self.slice.get_unchecked(self.last_end));
+        if self.unchanged {
+            self.last_start = start;
+            self.last_end = end;
+            #[cfg(any(test, feature = "rolling_trace"))]
+            self.trace.push((0, 0, self.buf.len()));
+            return &self.buf;
+        }
+
+        #[cfg(any(test, feature = "rolling_trace"))]
+        let (removed, inserted) = if start >= self.last_end {
+            (self.buf.len(), end - start)
+        } else {
+            (start - self.last_start, end - self.last_end)
+        };
+
         // swap the whole buffer
         if start >= self.last_end {
             self.buf.clear();
             let new_window = self.slice.get_unchecked(start..end);
             self.buf.extend_from_slice(new_window);
-            self.buf.sort_by(TotalOrd::tot_cmp);
+            sort_window(&mut self.buf, &mut self.low_cardinality);
         } else {
             // remove elements that should leave the window
-            for idx in self.last_start..start {
-                // safety
-                // we are in bounds
-                let val = self.slice.get_unchecked(idx);
-                // safety
-                // value is present in buf
-                let remove_idx = self
-                    .buf
-                    .binary_search_by(|a| a.tot_cmp(val))
-                    .unwrap_unchecked();
-                // this is O(n) but we need a sorted window
-                self.buf.remove(remove_idx);
+            match start - self.last_start {
+                0 => {},
+                1 => self.remove(self.last_start),
+                _ => self.bulk_remove(self.last_start, start),
             }
 
             // insert elements that enter the window, but insert them sorted
-            for idx in self.last_end..end {
-                // safety
-                // we are in bounds
-                let val = *self.slice.get_unchecked(idx);
-                let insertion_idx = self
-                    .buf
-                    .binary_search_by(|a| a.tot_cmp(&val))
-                    .unwrap_or_else(|insertion_idx| insertion_idx);
-
-                // this is O(n) but we need a sorted window
-                self.buf.insert(insertion_idx, val);
+            match end - self.last_end {
+                0 => {},
+                1 => self.insert(self.last_end),
+                _ => self.bulk_insert(self.last_end, end),
             }
         }
         self.last_start = start;
         self.last_end = end;
+        #[cfg(any(test, feature = "rolling_trace"))]
+        self.trace.push((removed, inserted, self.buf.len()));
         &self.buf
     }
+
+    /// The number of removed and inserted values and the resulting window length of every
+    /// call to `update`.
+    #[cfg(feature = "rolling_trace")]
+    pub fn trace(&self) -> &[(usize, usize, usize)] {
+        &self.trace
+    }
+
+    /// Whether the last call to [`update`](Self::update) left the values in the window
+    /// unchanged, in which case any aggregation of the previous window can be reused.
+    pub(super) fn is_unchanged(&self) -> bool {
+        self.unchanged
+    }
+
+    /// Remove the value at `idx` from the sorted buffer.
+    /// # Safety
+    /// The caller must ensure that `idx` is within bounds of `self.slice` and that its value
+    /// is in the buffer
+    unsafe fn remove(&mut self, idx: usize) {
+        let val = self.slice.get_unchecked(idx);
+        let remove_idx = binary_search_total(&self.buf, val).unwrap_unchecked();
+        // this is O(n) but we need a sorted window
+        self.buf.remove(remove_idx);
+    }
+
+    /// Remove the values at `start..end` from the sorted buffer in a single pass, instead of
+    /// shifting the buffer once per value.
+    /// # Safety
+    /// The caller must ensure that `start` and `end` are within bounds of `self.slice` and
+    /// that their values are in the buffer
+    unsafe fn bulk_remove(&mut self, start: usize, end: usize) {
+        let mut old = self.slice.get_unchecked(start..end).to_vec();
+        old.sort_by(TotalOrd::tot_cmp);
+
+        // both are sorted, so every value to remove is matched with the first equal value in
+        // the buffer that is still left
+        let mut j = 0;
+        self.buf.retain(|v| {
+            if j < old.len() && v.tot_eq(&old[j]) {
+                j += 1;
+                false
+            } else {
+                true
+            }
+        });
+        debug_assert_eq!(j, old.len());
+    }
+
+    /// Insert the value at `idx` into the sorted buffer.
+    /// # Safety
+    /// The caller must ensure that `idx` is within bounds of `self.slice`
+    unsafe fn insert(&mut self, idx: usize) {
+        let val = *self.slice.get_unchecked(idx);
+        let insertion_idx =
+            binary_search_total(&self.buf, &val).unwrap_or_else(|insertion_idx| insertion_idx);
+
+        // this is O(n) but we need a sorted window
+        self.buf.insert(insertion_idx, val);
+    }
+
+    /// Insert the values at `start..end` into the sorted buffer in a single merge pass,
+    /// instead of shifting the buffer once per value.
+    /// # Safety
+    /// The caller must ensure that `start` and `end` are within bounds of `self.slice`
+    unsafe fn bulk_insert(&mut self, start: usize, end: usize) {
+        let mut new = self.slice.get_unchecked(start..end).to_vec();
+        sort_window(&mut new, &mut self.low_cardinality);
+
+        // merge from the back, so that every value is moved at most once
+        let (mut i, mut j) = (self.buf.len(), new.len());
+        self.buf.extend_from_slice(&new);
+        while j > 0 {
+            let k = i + j - 1;
+            if i > 0 && self.buf[i - 1].tot_gt(&new[j - 1]) {
+                self.buf[k] = self.buf[i - 1];
+                i -= 1;
+            } else {
+                self.buf[k] = new[j - 1];
+                j -= 1;
+            }
+        }
+    }
 }
 
 pub(super) struct SortedBufNulls<'a, T: NativeType> {
@@ -79,6 +257,9 @@ pub(super) struct SortedBufNulls<'a, T: NativeType> {
     // values within the window that we keep sorted
     buf: Vec<Option<T>>,
     pub null_count: usize,
+    // (removed, inserted, window length) per call to `update`
+    #[cfg(any(test, feature = "rolling_trace"))]
+    trace: Vec<(usize, usize, usize)>,
 }
 
 impl<'a, T: NativeType> SortedBufNulls<'a, T> {
@@ -114,6 +295,8 @@ impl<'a, T: NativeType> SortedBufNulls<'a, T> {
             last_end: end,
             buf,
             null_count: 0,
+            #[cfg(any(test, feature = "rolling_trace"))]
+            trace: vec![],
         };
         out.fill_and_sort_buf(start, end);
         out
@@ -124,6 +307,13 @@ impl<'a, T: NativeType> SortedBufNulls<'a, T> {
     /// The caller must ensure that `start` and `end` are within bounds of `self.slice`
     ///
     pub(super) unsafe fn update(&mut self, start: usize, end: usize) -> (&[Option<T>], usize) {
+        #[cfg(any(test, feature = "rolling_trace"))]
+        let (removed, inserted) = if start >= self.last_end {
+            (self.buf.len(), end - start)
+        } else {
+            (start - self.last_start, end - self.last_end)
+        };
+
         // swap the whole buffer
         if start >= self.last_end {
             self.fill_and_sort_buf(start, end);
@@ -141,10 +331,7 @@ impl<'a, T: NativeType> SortedBufNulls<'a, T> {
 
                 // safety
                 // value is present in buf
-                let remove_idx = self
-                    .buf
-                    .binary_search_by(|a| a.tot_cmp(&val))
-                    .unwrap_unchecked();
+                let remove_idx = binary_search_total(&self.buf, &val).unwrap_unchecked();
                 // this is O(n) but we need a sorted window
                 self.buf.remove(remove_idx);
             }
@@ -159,9 +346,7 @@ impl<'a, T: NativeType> SortedBufNulls<'a, T> {
                     self.null_count += 1;
                     None
                 };
-                let insertion_idx = self
-                    .buf
-                    .binary_search_by(|a| a.tot_cmp(&val))
+                let insertion_idx = binary_search_total(&self.buf, &val)
                     .unwrap_or_else(|insertion_idx| insertion_idx);
 
                 // this is O(n) but we need a sorted window
@@ -170,16 +355,27 @@ impl<'a, T: NativeType> SortedBufNulls<'a, T> {
         }
         self.last_start = start;
         self.last_end = end;
+        #[cfg(any(test, feature = "rolling_trace"))]
+        self.trace.push((removed, inserted, self.buf.len()));
         (&self.buf, self.null_count)
     }
 
     pub(super) fn is_valid(&self, min_periods: usize) -> bool {
         ((self.last_end - self.last_start) - self.null_count) >= min_periods
     }
+
+    /// The number of removed and inserted values and the resulting window length of every
+    /// call to `update`.
+    #[cfg(feature = "rolling_trace")]
+    pub fn trace(&self) -> &[(usize, usize, usize)] {
+        &self.trace
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use polars_utils::total_ord::TotalEq;
+
     use super::*;
 
     #[test]
@@ -200,4 +396,216 @@ mod test {
             assert_eq!(window, &[-1, 2, 9]);
         }
     }
+
+    #[test]
+    fn test_sorted_buf_bulk_insert() {
+        let values = &[7, 1, 5, 3, 9, -2, 5, 0, 4, 8];
+        for (start, end) in [(0, 0), (0, 3), (2, 5), (4, 4)] {
+            let mut incremental = SortedBuf::new(values, start, end);
+            let mut bulk = SortedBuf::new(values, start, end);
+            unsafe {
+                for idx in end..values.len() {
+                    incremental.insert(idx);
+                }
+                bulk.bulk_insert(end, values.len());
+            }
+            assert_eq!(bulk.buf, incremental.buf);
+        }
+    }
+
+    #[test]
+    fn test_sorted_buf_bulk_remove() {
+        let values = &[5, 1, 4, 1, 3, 9, 2, 6, 5, 3, 5, 8, 9, 7];
+        for (start, end) in [(0, 2), (0, 7), (3, 9), (0, 14)] {
+            let mut bulk = SortedBuf::new(values, 0, values.len());
+            let mut single = SortedBuf::new(values, 0, values.len());
+            unsafe {
+                bulk.bulk_remove(start, end);
+                for idx in start..end {
+                    single.remove(idx);
+                }
+            }
+            assert_eq!(bulk.buf, single.buf);
+        }
+
+        // Sliding by more than one value takes the bulk path.
+        let mut sorted = SortedBuf::new(values, 0, 6);
+        let out = unsafe { sorted.update(4, 10) };
+        assert_eq!(out, SortedBuf::new(values, 4, 10).buf);
+    }
+
+    #[test]
+    fn test_sorted_buf_trace() {
+        let values = &[5.0f64, 1.0, 4.0, 2.0, 3.0, 8.0];
+        let len = values.len();
+        let (start, end) = det_offsets(0, 3, len);
+        let mut sorted = SortedBuf::new(values, start, end);
+        for idx in 0..len {
+            let (start, end) = det_offsets(idx, 3, len);
+            unsafe { sorted.update(start, end) };
+        }
+        // The window grows to its full size and then slides one element per step.
+        assert_eq!(
+            sorted.trace,
+            &[
+                (0, 0, 1),
+                (0, 1, 2),
+                (0, 1, 3),
+                (1, 1, 3),
+                (1, 1, 3),
+                (1, 1, 3)
+            ]
+        );
+        assert_eq!(sorted.buf, &[2.0, 3.0, 8.0]);
+
+        // Jumping past the current window replaces the whole buffer.
+        let mut sorted = SortedBuf::new(values, 0, 2);
+        unsafe { sorted.update(3, 5) };
+        assert_eq!(sorted.trace, &[(2, 2, 2)]);
+        assert_eq!(sorted.buf, &[2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_sorted_buf_unchanged() {
+        let values = &[1, 2, 1, 2, 3, 2, 2];
+        let mut sorted = SortedBuf::new(values, 0, 2);
+        let mut unchanged = vec![];
+        for (start, end) in [(0, 2), (1, 3), (2, 4), (3, 5), (4, 6), (5, 7)] {
+            let fresh = SortedBuf::new(values, start, end);
+            assert_eq!(unsafe { sorted.update(start, end) }, fresh.buf);
+            unchanged.push(sorted.is_unchanged());
+        }
+        assert_eq!(unchanged, &[false, true, true, false, true, false]);
+        assert_eq!(sorted.trace[1], (0, 0, 2));
+    }
+
+    #[test]
+    fn test_sorted_buf_reset_and_resume() {
+        let values = &[4, 1, 7, 1, 0, 9, 3, 3, 8, 2, 6, 5];
+        let validity = [
+            true, false, true, true, false, false, true, true, false, true, true, true,
+        ]
+        .into_iter()
+        .collect::<Bitmap>();
+        // Slides, jumps past the window, empty windows and a jump straight after an empty
+        // window, in that order. Every state must match a buffer that was built from scratch.
+        let windows = [
+            (0, 2),
+            (1, 4),
+            (5, 7),
+            (6, 7),
+            (7, 7),
+            (7, 7),
+            (8, 10),
+            (8, 12),
+            (12, 12),
+        ];
+
+        let mut sorted = SortedBuf::new(values, 0, 0);
+        let mut sorted_nulls = unsafe { SortedBufNulls::new(values, &validity, 0, 0) };
+        for (start, end) in windows {
+            let fresh = SortedBuf::new(values, start, end);
+            assert_eq!(unsafe { sorted.update(start, end) }, fresh.buf);
+
+            let fresh = unsafe { SortedBufNulls::new(values, &validity, start, end) };
+            let (buf, null_count) = unsafe { sorted_nulls.update(start, end) };
+            assert_eq!(buf, fresh.buf, "window {start}..{end}");
+            assert_eq!(null_count, fresh.null_count, "window {start}..{end}");
+            for min_periods in 0..=3 {
+                assert_eq!(
+                    sorted_nulls.is_valid(min_periods),
+                    fresh.is_valid(min_periods)
+                );
+            }
+        }
+        // Both buffers replace their contents on the same steps.
+        assert_eq!(sorted.trace, sorted_nulls.trace);
+        assert_eq!(sorted_nulls.trace[2], (3, 2, 2));
+    }
+
+    #[test]
+    fn test_counting_sort() {
+        let mut values = vec![
+            3.0,
+            f64::NAN,
+            -1.0,
+            3.0,
+            0.0,
+            -0.0,
+            2.5,
+            3.0,
+            -1.0,
+            f64::NAN,
+        ];
+        let mut expected = values.clone();
+        expected.sort_by(TotalOrd::tot_cmp);
+        assert!(counting_sort(&mut values));
+        assert!(values.iter().zip(&expected).all(|(a, b)| a.tot_eq(b)));
+        // Both zeros are kept.
+        assert_eq!(
+            values
+                .iter()
+                .filter(|v| v.is_sign_negative() && **v == 0.0)
+                .count(),
+            1
+        );
+
+        let mut values = (0..9).rev().collect::<Vec<i32>>();
+        assert!(!counting_sort(&mut values));
+        assert_eq!(values, (0..9).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sorted_buf_low_cardinality() {
+        // Few distinct values first, then one distinct value per row.
+        let mut values = (0..200).map(|i| (i * 7 % 3) as i64).collect::<Vec<_>>();
+        values.extend(100..150);
+
+        let check = |sorted: &SortedBuf<i64>, start: usize, end: usize| {
+            let mut expected = values[start..end].to_vec();
+            expected.sort();
+            assert_eq!(sorted.buf, expected, "window {start}..{end}");
+        };
+
+        let mut sorted = SortedBuf::new(&values, 0, 20);
+        assert!(sorted.low_cardinality);
+        // Slides, bulk inserts and jumps past the window.
+        for (start, end) in [(1, 21), (1, 30), (40, 60), (45, 70), (100, 120)] {
+            unsafe { sorted.update(start, end) };
+            check(&sorted, start, end);
+        }
+        assert!(sorted.low_cardinality);
+
+        for (start, end) in [(190, 210), (220, 240), (225, 250)] {
+            unsafe { sorted.update(start, end) };
+            check(&sorted, start, end);
+        }
+        assert!(!sorted.low_cardinality);
+    }
+
+    #[test]
+    fn test_sorted_buf_from_sorted() {
+        let unsorted = &[3.0f64, -0.0, 1.0, 0.0, 3.0, 2.0, 1.0, 4.0, 3.5];
+        let mut sorted = unsorted.to_vec();
+        sorted.sort_by(TotalOrd::tot_cmp);
+
+        // Both sorted the same values, so they must be in the same state.
+        let from_sorted = SortedBuf::from_sorted(&sorted, 0, sorted.len());
+        let from_unsorted = SortedBuf::new(unsorted, 0, unsorted.len());
+        assert_eq!(from_sorted.buf, from_unsorted.buf);
+        assert_eq!(from_sorted.low_cardinality, from_unsorted.low_cardinality);
+
+        // A sorted first window takes the same path in `new` and keeps working afterwards.
+        for (start, end) in [(0, 3), (0, 9), (2, 7)] {
+            let mut a = SortedBuf::from_sorted(&sorted, start, end);
+            let mut b = SortedBuf::new(&sorted, start, end);
+            assert_eq!(a.buf, b.buf);
+            assert_eq!(a.low_cardinality, b.low_cardinality);
+            for (start, end) in [(start + 1, end), (4, 9), (8, 9)] {
+                let expected = SortedBuf::new(&sorted, start, end).buf;
+                assert_eq!(unsafe { a.update(start, end) }, expected);
+                assert_eq!(unsafe { b.update(start, end) }, expected);
+            }
+        }
+    }
 }
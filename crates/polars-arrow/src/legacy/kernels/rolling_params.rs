@@ -0,0 +1,20 @@
+//! The parameters of the rolling kernels. These are part of the options of rolling operations
+//! in the higher-level crates, so they are available without the `rolling` feature.
+use std::any::Any;
+use std::sync::Arc;
+
+use super::QuantileInterpolOptions;
+
+pub type DynArgs = Option<Arc<dyn Any + Sync + Send>>;
+
+// Parameters allowed for rolling operations.
+#[derive(Clone, Copy, Debug)]
+pub struct RollingVarParams {
+    pub ddof: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RollingQuantileParams {
+    pub prob: f64,
+    pub interpol: QuantileInterpolOptions,
+}
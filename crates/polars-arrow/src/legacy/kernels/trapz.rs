@@ -0,0 +1,104 @@
+use num_traits::AsPrimitive;
+use polars_utils::float::IsFloat;
+
+use crate::types::NativeType;
+
+/// The cumulative integral of `y` over `x` by the trapezoidal rule.
+///
+/// Output `[i]` is the integral of `y` from `x[0]` to `x[i]`, so the first value is always `0.0`.
+/// If `x` is `None` the samples are assumed to have unit spacing.
+///
+/// # Panics
+/// Panics if `x` is given and does not have the same length as `y`.
+pub fn cumulative_trapz<T>(y: &[T], x: Option<&[T]>) -> Vec<f64>
+where
+    T: NativeType + IsFloat + AsPrimitive<f64>,
+{
+    let mut out = Vec::with_capacity(y.len());
+    let Some(first) = y.first() else {
+        return out;
+    };
+    let mut acc = 0.0;
+    out.push(acc);
+    match x {
+        Some(x) => {
+            assert_eq!(x.len(), y.len());
+            let mut prev = (x[0].as_(), first.as_());
+            for (x, y) in x[1..].iter().zip(&y[1..]) {
+                let point = (x.as_(), y.as_());
+                acc += trapezoid(prev, point);
+                out.push(acc);
+                prev = point;
+            }
+        },
+        None => {
+            let mut prev = first.as_();
+            for y in &y[1..] {
+                let y = y.as_();
+                acc += 0.5 * (prev + y);
+                out.push(acc);
+                prev = y;
+            }
+        },
+    }
+    out
+}
+
+/// [`cumulative_trapz`] over nullable `(x, y)` points.
+///
+/// A point where either `x` or `y` is null produces a null and is skipped: the next trapezoid
+/// spans from the last non-null point to the next non-null point.
+pub fn cumulative_trapz_iter<I>(iter: I) -> Vec<Option<f64>>
+where
+    I: IntoIterator<Item = (Option<f64>, Option<f64>)>,
+{
+    let iter = iter.into_iter();
+    let mut out = Vec::with_capacity(iter.size_hint().0);
+    let mut acc = 0.0;
+    let mut prev = None;
+    for (x, y) in iter {
+        match (x, y) {
+            (Some(x), Some(y)) => {
+                if let Some(prev) = prev {
+                    acc += trapezoid(prev, (x, y));
+                }
+                prev = Some((x, y));
+                out.push(Some(acc));
+            },
+            _ => out.push(None),
+        }
+    }
+    out
+}
+
+#[inline]
+fn trapezoid((x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> f64 {
+    0.5 * (x1 - x0) * (y0 + y1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cumulative_trapz() {
+        assert_eq!(cumulative_trapz::<f64>(&[], None), &[] as &[f64]);
+        assert_eq!(cumulative_trapz(&[1.0, 2.0, 3.0], None), &[0.0, 1.5, 4.0]);
+        assert_eq!(
+            cumulative_trapz(&[1.0f32, 1.0, 3.0], Some(&[0.0, 2.0, 3.0])),
+            &[0.0, 2.0, 4.0]
+        );
+        assert_eq!(cumulative_trapz(&[2i32, 4], Some(&[1, 3])), &[0.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cumulative_trapz_nulls() {
+        let x = [Some(0.0), Some(1.0), Some(2.0), None, Some(4.0)];
+        let y = [None, Some(1.0), None, Some(5.0), Some(3.0)];
+        // The only trapezoid spans (1.0, 1.0) to (4.0, 3.0).
+        assert_eq!(
+            cumulative_trapz_iter(x.into_iter().zip(y)),
+            &[None, Some(0.0), None, None, Some(6.0)]
+        );
+    }
+}
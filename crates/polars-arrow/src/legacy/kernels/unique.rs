@@ -0,0 +1,49 @@
+use std::hash::Hash;
+
+use ahash::AHashSet;
+
+use crate::types::NativeType;
+
+/// The number of distinct values in `slice[..=i]` for every `i`.
+pub fn cumulative_unique_count<T: NativeType + Eq + Hash>(slice: &[T]) -> Vec<u32> {
+    cumulative_unique_count_iter(slice.iter())
+}
+
+/// The number of distinct values seen so far, for every value of `iter`.
+///
+/// This is [`cumulative_unique_count`] for values that are not in a slice, e.g. nullable
+/// values where `None` counts as one more distinct value.
+pub fn cumulative_unique_count_iter<I>(iter: I) -> Vec<u32>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    let iter = iter.into_iter();
+    let mut seen = AHashSet::new();
+    let mut out = Vec::with_capacity(iter.size_hint().0);
+    for v in iter {
+        seen.insert(v);
+        out.push(seen.len() as u32);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cumulative_unique_count() {
+        assert_eq!(cumulative_unique_count::<i32>(&[]), &[] as &[u32]);
+        assert_eq!(cumulative_unique_count(&[3, 1, 2, 5]), &[1, 2, 3, 4]);
+        assert_eq!(cumulative_unique_count(&[7u8; 4]), &[1, 1, 1, 1]);
+        assert_eq!(
+            cumulative_unique_count(&[1i64, 2, 1, 3, 2, 4, 4]),
+            &[1, 2, 2, 3, 3, 4, 4]
+        );
+        assert_eq!(
+            cumulative_unique_count_iter([Some(1), None, Some(1), None, Some(2)]),
+            &[1, 2, 2, 2, 3]
+        );
+    }
+}
@@ -62,7 +62,10 @@ impl<'a> AnonymousListBuilder<'a> {
         match s.dtype() {
             // Empty arrays tend to be null type and thus differ
             // if we would push it the concat would fail.
-            DataType::Null if s.is_empty() => self.append_empty(),
+            DataType::Null if s.is_empty() => {
+                self.append_empty();
+                return Ok(());
+            },
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => {
                 let arr = &**s.array_ref(0);
@@ -71,6 +74,9 @@ impl<'a> AnonymousListBuilder<'a> {
             },
             dt => self.inner_dtype.update(dt)?,
         }
+        if s.is_empty() {
+            self.fast_explode = false;
+        }
         self.builder.push_multiple(s.chunks());
         Ok(())
     }
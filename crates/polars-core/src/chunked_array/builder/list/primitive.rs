@@ -13,12 +13,19 @@ impl<T> ListPrimitiveChunkedBuilder<T>
 where
     T: PolarsNumericType,
 {
+    /// Create a builder for a list with inner dtype `logical_type`, which must have `T` as its
+    /// physical type.
+    ///
+    /// The values are appended in their physical representation and the finished list gets the
+    /// logical inner dtype as is, e.g. a `List(Datetime(tu, tz))` can be built from `i64` values
+    /// without casting the output.
     pub fn new(
         name: &str,
         capacity: usize,
         values_capacity: usize,
         logical_type: DataType,
     ) -> Self {
+        debug_assert_eq!(logical_type.to_physical(), T::get_dtype());
         let values = MutablePrimitiveArray::<T::Native>::with_capacity(values_capacity);
         let builder = LargePrimitiveBuilder::<T::Native>::new_with_capacity(values, capacity);
         let field = Field::new(name, DataType::List(Box::new(logical_type)));
@@ -30,6 +37,12 @@ where
         }
     }
 
+    /// Reserve capacity for `additional_rows` lists that hold `additional_values` values in total.
+    pub fn reserve(&mut self, additional_rows: usize, additional_values: usize) {
+        self.builder.reserve(additional_rows);
+        self.builder.mut_values().reserve(additional_values);
+    }
+
     #[inline]
     pub fn append_slice(&mut self, items: &[T::Native]) {
         let values = self.builder.mut_values();
@@ -64,6 +77,28 @@ where
         self.builder.try_push_valid().unwrap();
     }
 
+    /// Appends a list of non-null values from an iterator of unknown length.
+    #[inline]
+    pub fn append_values_iter<I: Iterator<Item = T::Native>>(&mut self, iter: I) {
+        let values = self.builder.mut_values();
+        let len = values.values().len();
+        values.extend_values(iter);
+
+        if values.values().len() == len {
+            self.fast_explode = false;
+        }
+        // overflow of i64 is far beyond polars capable lengths.
+        unsafe { self.builder.try_push_valid().unwrap_unchecked() };
+    }
+
+    /// Appends an empty, valid list.
+    #[inline]
+    pub fn append_empty(&mut self) {
+        self.fast_explode = false;
+        // overflow of i64 is far beyond polars capable lengths.
+        unsafe { self.builder.try_push_valid().unwrap_unchecked() };
+    }
+
     /// Appends from an iterator over values
     #[inline]
     pub fn append_iter<I: Iterator<Item = Option<T::Native>> + TrustedLen>(&mut self, iter: I) {
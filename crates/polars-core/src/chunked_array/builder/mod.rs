@@ -256,4 +256,96 @@ mod test {
         assert_eq!(out.len(), 7);
         assert_eq!(out.get(6).unwrap(), AnyValue::Null);
     }
+
+    #[test]
+    fn test_list_builder_values_iter() {
+        let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new("a", 0, 0, DataType::Int64);
+        builder.reserve(3, 6);
+        builder.append_values_iter((0..3).map(|v| v * 2));
+        builder.append_null();
+        builder.append_values_iter([7, 8, 9].into_iter().filter(|v| v % 2 == 1));
+
+        let out = builder.finish();
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.null_count(), 1);
+        let first = out.get_as_series(0).unwrap();
+        assert_eq!(
+            Vec::from(first.i64().unwrap()),
+            &[Some(0), Some(2), Some(4)]
+        );
+        let last = out.get_as_series(2).unwrap();
+        assert_eq!(Vec::from(last.i64().unwrap()), &[Some(7), Some(9)]);
+
+        let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new("a", 1, 0, DataType::Int64);
+        builder.append_values_iter(std::iter::empty());
+        let out = builder.finish();
+        assert_eq!(out.get_as_series(0).unwrap().len(), 0);
+        assert!(!out._can_fast_explode());
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_list_builder_logical_inner() {
+        let dtype = DataType::Datetime(TimeUnit::Nanoseconds, Some("Europe/Amsterdam".into()));
+        let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new("a", 2, 3, dtype.clone());
+        builder.append_slice(&[1, 2]);
+        builder.append_null();
+        let out = builder.finish();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(dtype.clone())));
+        assert_eq!(out.inner_dtype(), dtype);
+
+        // The values are stored as appended.
+        let first = out.get_as_series(0).unwrap();
+        assert_eq!(first.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(first.to_physical_repr().i64().unwrap()),
+            &[Some(1), Some(2)]
+        );
+
+        let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new("a", 1, 1, DataType::Date);
+        builder.append_slice(&[19000]);
+        let out = builder.finish();
+        assert_eq!(out.inner_dtype(), DataType::Date);
+        assert_eq!(
+            out.get_as_series(0).unwrap().date().unwrap().get(0),
+            Some(19000)
+        );
+    }
+
+    #[test]
+    fn test_list_builder_append_empty() {
+        let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new("a", 2, 0, DataType::Int64);
+        builder.append_empty();
+        builder.append_empty();
+        let out = builder.finish();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.null_count(), 0);
+        assert_eq!(out.get_as_series(1).unwrap().len(), 0);
+        assert!(!out._can_fast_explode());
+
+        let mut builder = AnonymousListBuilder::new("a", 2, Some(DataType::Int64));
+        builder.append_empty();
+        builder.append_null();
+        let out = builder.finish();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.null_count(), 1);
+
+        // An empty `Null` series is a single empty list.
+        let empty = Series::new_empty("", &DataType::Null);
+        let mut builder = AnonymousListBuilder::new("a", 1, Some(DataType::Int64));
+        builder.append_series(&empty).unwrap();
+        let out = builder.finish();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(out.len(), 1);
+
+        let mut builder = AnonymousOwnedListBuilder::new("a", 2, Some(DataType::Int64));
+        builder.append_empty();
+        builder.append_series(&empty).unwrap();
+        let out = builder.finish();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.null_count(), 0);
+    }
 }
@@ -282,7 +282,9 @@ where
         Self::with_chunk(name, to_primitive::<T>(v, None))
     }
 
-    /// Create a new ChunkedArray from a Vec and a validity mask.
+    /// Create a new ChunkedArray from a Vec and a validity mask. This operation is zero copy.
+    ///
+    /// The `buffer` must have the same length as `values`, a cleared bit marks a null.
     pub fn from_vec_validity(name: &str, values: Vec<T::Native>, buffer: Option<Bitmap>) -> Self {
         let arr = to_array::<T>(values, buffer);
         let mut out = ChunkedArray {
@@ -392,6 +392,17 @@ where
         unsafe { arr.get_unchecked(arr_idx) }
     }
 
+    /// Get the only value of a [`ChunkedArray`] of length one, e.g. a broadcast argument.
+    ///
+    /// Errors if the length is not one. A null value gives `Ok(None)`.
+    pub fn get_scalar(&self) -> PolarsResult<Option<T::Physical<'_>>> {
+        polars_ensure!(
+            self.len() == 1,
+            ComputeError: "expected a single value for `{}`, got {}", self.name(), self.len()
+        );
+        Ok(self.get(0))
+    }
+
     /// # Safety
     /// It is the callers responsibility that the `idx < self.len()`.
     #[inline]
@@ -588,6 +599,24 @@ where
         self.downcast_iter().map(|arr| arr.values().as_slice())
     }
 
+    /// Iterate over the windows of `k` consecutive values, starting a new window every `step`
+    /// values.
+    ///
+    /// Returns `None` if the data is not in a single chunk, or if `k` or `step` is zero. Like
+    /// [`ChunkedArray::data_views`], the windows contain the raw values: null values should be
+    /// taken into account by the user.
+    pub fn iter_windows(
+        &self,
+        k: usize,
+        step: usize,
+    ) -> Option<impl Iterator<Item = &[T::Native]> + ExactSizeIterator> {
+        if self.chunks.len() != 1 || k == 0 || step == 0 {
+            return None;
+        }
+        let values = self.downcast_iter().next().unwrap().values().as_slice();
+        Some(values.windows(k).step_by(step))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub fn into_no_null_iter(
         &self,
@@ -699,6 +728,33 @@ pub(crate) mod test {
         assert!(a.is_sorted_ascending_flag());
     }
 
+    #[test]
+    fn test_iter_windows() {
+        let a = Int32Chunked::new("a", &[1, 2, 3, 4, 5]);
+        let windows = a.iter_windows(3, 1).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, [&[1, 2, 3], &[2, 3, 4], &[3, 4, 5]]);
+        let windows = a.iter_windows(2, 2).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, [&[1, 2], &[3, 4]]);
+        assert_eq!(a.iter_windows(6, 1).unwrap().len(), 0);
+        assert!(a.iter_windows(0, 1).is_none());
+
+        let mut b = a.clone();
+        b.append(&a);
+        assert!(b.iter_windows(2, 1).is_none());
+    }
+
+    #[test]
+    fn test_get_scalar() {
+        let a = Int32Chunked::new("start", &[Some(1), None, Some(3)]);
+        assert_eq!(a.slice(0, 1).get_scalar().unwrap(), Some(1));
+        assert_eq!(a.slice(1, 1).get_scalar().unwrap(), None);
+        let err = a.get_scalar().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected a single value for `start`, got 3"));
+        assert!(a.clear().get_scalar().is_err());
+    }
+
     #[test]
     fn arithmetic() {
         let a = &Int32Chunked::new("a", &[1, 100, 6, 40]);
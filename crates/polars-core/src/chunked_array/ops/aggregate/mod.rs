@@ -593,9 +593,7 @@ impl<T: PolarsObject> ChunkAggSeries for ObjectChunked<T> {}
 
 #[cfg(test)]
 mod test {
-    use arrow::legacy::prelude::QuantileInterpolOptions;
-
-    use crate::prelude::*;
+    use crate::prelude::{QuantileInterpolOptions, *};
 
     #[test]
     fn test_var() {
@@ -1086,4 +1084,59 @@ mod test {
         assert!(a.median_as_series().equals_missing(&expected));
         assert_eq!(a.median(), Some(2.0f64))
     }
+
+    #[test]
+    fn test_quantile_linear_interpolation() {
+        // Interpolated as `lower + (upper - lower) * proportion`.
+        let ca = Float64Chunked::from_slice("", &[0.7, 0.1]);
+        let out = ca.quantile(0.7, QuantileInterpolOptions::Linear).unwrap();
+        assert_eq!(out, Some(0.52));
+        let ca = Float64Chunked::from_slice("", &[0.3, 0.1]);
+        let out = ca.quantile(0.3, QuantileInterpolOptions::Linear).unwrap();
+        assert_eq!(out, Some(0.16));
+    }
+
+    #[test]
+    fn test_quantile_matches_rolling_quantile() {
+        use std::any::Any;
+        use std::sync::Arc;
+
+        use arrow::legacy::kernels::rolling::no_nulls::rolling_quantile;
+        use arrow::legacy::kernels::rolling::RollingQuantileParams;
+
+        let values = (0..40)
+            .map(|v| ((v * 37) % 17) as f64 / 3.0 - 2.0)
+            .collect::<Vec<_>>();
+        let window_size = 7;
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            for prob in [0.0, 0.1, 0.25, 0.3, 0.5, 0.7, 0.9, 1.0] {
+                let params = Some(Arc::new(RollingQuantileParams { prob, interpol })
+                    as Arc<dyn Any + Send + Sync>);
+                let rolling =
+                    rolling_quantile(&values, window_size, window_size, false, None, params)
+                        .unwrap();
+                let rolling = rolling
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f64>>()
+                    .unwrap();
+
+                for end in window_size..=values.len() {
+                    let window = Float64Chunked::from_slice("", &values[end - window_size..end]);
+                    let expected = window.quantile(prob, interpol).unwrap().unwrap();
+                    let out = rolling.value(end - 1);
+                    assert_eq!(
+                        out.to_bits(),
+                        expected.to_bits(),
+                        "{interpol:?} {prob} at {end}: {out} != {expected}"
+                    );
+                }
+            }
+        }
+    }
 }
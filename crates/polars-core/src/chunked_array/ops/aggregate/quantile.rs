@@ -1,4 +1,8 @@
-use arrow::legacy::prelude::QuantileInterpolOptions;
+use arrow::legacy::kernels::quantile_index;
+// Defined next to the quantile kernels in polars-arrow, which can't depend on polars-core. This
+// is its public path, through the prelude.
+pub use arrow::legacy::kernels::QuantileInterpolOptions;
+use polars_utils::functions::lerp;
 
 use super::*;
 
@@ -13,51 +17,6 @@ pub trait QuantileAggSeries {
     ) -> PolarsResult<Series>;
 }
 
-/// helper
-fn quantile_idx(
-    quantile: f64,
-    length: usize,
-    null_count: usize,
-    interpol: QuantileInterpolOptions,
-) -> (usize, f64, usize) {
-    let mut base_idx = match interpol {
-        QuantileInterpolOptions::Nearest => {
-            (((length - null_count) as f64) * quantile + null_count as f64) as usize
-        },
-        QuantileInterpolOptions::Lower
-        | QuantileInterpolOptions::Midpoint
-        | QuantileInterpolOptions::Linear => {
-            (((length - null_count) as f64 - 1.0) * quantile + null_count as f64) as usize
-        },
-        QuantileInterpolOptions::Higher => {
-            (((length - null_count) as f64 - 1.0) * quantile + null_count as f64).ceil() as usize
-        },
-    };
-
-    base_idx = base_idx.clamp(0, length - 1);
-    let float_idx = ((length - null_count) as f64 - 1.0) * quantile + null_count as f64;
-    let top_idx = f64::ceil(float_idx) as usize;
-
-    (base_idx, float_idx, top_idx)
-}
-
-/// helper
-fn linear_interpol<T: Float>(lower: T, upper: T, idx: usize, float_idx: f64) -> T {
-    if lower == upper {
-        lower
-    } else {
-        let proportion: T = T::from(float_idx).unwrap() - T::from(idx).unwrap();
-        proportion * (upper - lower) + lower
-    }
-}
-fn midpoint_interpol<T: Float>(lower: T, upper: T) -> T {
-    if lower == upper {
-        lower
-    } else {
-        (lower + upper) / (T::one() + T::one())
-    }
-}
-
 // Uses quickselect instead of sorting all data
 fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     vals: &mut [T],
@@ -73,32 +32,18 @@ fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     if vals.len() == 1 {
         return Ok(vals[0].to_f64());
     }
-    let (idx, float_idx, top_idx) = quantile_idx(quantile, vals.len(), 0, interpol);
+    let (idx, top_idx, proportion) = quantile_index(vals.len(), quantile, interpol);
 
     let (_lhs, lower, rhs) = vals.select_nth_unstable_by(idx, TotalOrd::tot_cmp);
     if idx == top_idx {
         Ok(lower.to_f64())
     } else {
-        match interpol {
-            QuantileInterpolOptions::Midpoint => {
-                let upper = rhs.iter().copied().min_by(TotalOrd::tot_cmp).unwrap();
-                Ok(Some(midpoint_interpol(
-                    lower.to_f64().unwrap(),
-                    upper.to_f64().unwrap(),
-                )))
-            },
-            QuantileInterpolOptions::Linear => {
-                let upper = rhs.iter().copied().min_by(TotalOrd::tot_cmp).unwrap();
-                Ok(linear_interpol(
-                    lower.to_f64().unwrap(),
-                    upper.to_f64().unwrap(),
-                    idx,
-                    float_idx,
-                )
-                .to_f64())
-            },
-            _ => Ok(lower.to_f64()),
-        }
+        let upper = rhs.iter().copied().min_by(TotalOrd::tot_cmp).unwrap();
+        Ok(Some(lerp(
+            lower.to_f64().unwrap(),
+            upper.to_f64().unwrap(),
+            proportion,
+        )))
     }
 }
 
@@ -122,31 +67,18 @@ where
         return Ok(None);
     }
 
-    let (idx, float_idx, top_idx) = quantile_idx(quantile, length, null_count, interpol);
+    let (idx, top_idx, proportion) = quantile_index(length - null_count, quantile, interpol);
+    // The nulls are sorted first.
     let sorted = ca.sort(false);
-    let lower = sorted.get(idx).map(|v| v.to_f64().unwrap());
-
-    let opt = match interpol {
-        QuantileInterpolOptions::Midpoint => {
-            if top_idx == idx {
-                lower
-            } else {
-                let upper = sorted.get(idx + 1).map(|v| v.to_f64().unwrap());
-                midpoint_interpol(lower.unwrap(), upper.unwrap()).to_f64()
-            }
-        },
-        QuantileInterpolOptions::Linear => {
-            if top_idx == idx {
-                lower
-            } else {
-                let upper = sorted.get(idx + 1).map(|v| v.to_f64().unwrap());
-
-                linear_interpol(lower.unwrap(), upper.unwrap(), idx, float_idx).to_f64()
-            }
-        },
-        _ => lower,
-    };
-    Ok(opt)
+    let lower = sorted.get(null_count + idx).map(|v| v.to_f64().unwrap());
+    if idx == top_idx {
+        Ok(lower)
+    } else {
+        let upper = sorted
+            .get(null_count + top_idx)
+            .map(|v| v.to_f64().unwrap());
+        Ok(Some(lerp(lower.unwrap(), upper.unwrap(), proportion)))
+    }
 }
 
 impl<T> ChunkQuantile<f64> for ChunkedArray<T>
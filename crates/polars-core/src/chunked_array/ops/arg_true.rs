@@ -0,0 +1,92 @@
+use arrow::bitmap::Bitmap;
+
+use crate::prelude::*;
+use crate::series::IsSorted;
+
+/// Push the positions of the set bits of `word` to `out`, offset by `base`.
+#[inline]
+fn push_set_bits(out: &mut Vec<IdxSize>, mut word: u64, base: IdxSize) {
+    if word == u64::MAX {
+        out.extend(base..base + 64);
+        return;
+    }
+    while word != 0 {
+        out.push(base + word.trailing_zeros() as IdxSize);
+        // Clear the lowest set bit.
+        word &= word - 1;
+    }
+}
+
+fn extend_set_bits(out: &mut Vec<IdxSize>, values: &Bitmap, mut base: IdxSize) {
+    let chunks = values.chunks::<u64>();
+    let remainder_len = chunks.remainder_len();
+    let remainder = chunks.remainder();
+    for word in chunks {
+        push_set_bits(out, word, base);
+        base += 64;
+    }
+    if remainder_len > 0 {
+        // The bits after the end of the bitmap are not guaranteed to be unset.
+        let mask = (1u64 << remainder_len) - 1;
+        push_set_bits(out, remainder & mask, base);
+    }
+}
+
+impl BooleanChunked {
+    /// Get the indices of the `true` values; nulls count as `false`.
+    ///
+    /// The values are scanned 64 at a time.
+    pub fn arg_true(&self) -> IdxCa {
+        let mut out = Vec::with_capacity(self.sum().unwrap() as usize);
+        let mut offset = 0;
+        for arr in self.downcast_iter() {
+            match arr.validity() {
+                Some(validity) if validity.unset_bits() > 0 => {
+                    extend_set_bits(&mut out, &(validity & arr.values()), offset)
+                },
+                _ => extend_set_bits(&mut out, arr.values(), offset),
+            }
+            offset += arr.len() as IdxSize;
+        }
+        let mut out = IdxCa::from_vec(self.name(), out);
+        out.set_sorted_flag(IsSorted::Ascending);
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg_true() {
+        for len in [0, 1, 63, 64, 65, 130] {
+            for offset in [0, 3] {
+                let values = (0..len + offset)
+                    .map(|i| match i % 5 {
+                        0 => None,
+                        1 | 3 => Some(true),
+                        _ => Some(false),
+                    })
+                    .collect::<BooleanChunked>()
+                    .slice(offset as i64, len);
+                let expected = values
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, v)| *v == Some(true))
+                    .map(|(i, _)| i as IdxSize)
+                    .collect::<Vec<_>>();
+                let out = values.arg_true();
+                assert_eq!(out.into_no_null_iter().collect::<Vec<_>>(), expected);
+            }
+        }
+
+        // All set words and several chunks.
+        let mut values = BooleanChunked::full("a", true, 130);
+        values.append(&BooleanChunked::new("a", &[false, true]));
+        let out = values.arg_true();
+        assert_eq!(out.len(), 131);
+        assert_eq!(out.get(129), Some(129));
+        assert_eq!(out.get(130), Some(131));
+    }
+}
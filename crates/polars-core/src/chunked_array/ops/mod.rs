@@ -1,15 +1,15 @@
 //! Traits for miscellaneous operations on ChunkedArray
-use arrow::legacy::prelude::QuantileInterpolOptions;
 use arrow::offset::OffsetsBuffer;
 
 #[cfg(feature = "object")]
 use crate::datatypes::ObjectType;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 
 pub(crate) mod aggregate;
 pub(crate) mod any_value;
 pub(crate) mod append;
 mod apply;
+mod arg_true;
 pub mod arity;
 mod bit_repr;
 pub(crate) mod chunkops;
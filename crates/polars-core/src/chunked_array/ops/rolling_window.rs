@@ -1,4 +1,5 @@
 use arrow::legacy::prelude::DynArgs;
+use polars_error::{polars_ensure, PolarsResult};
 
 #[derive(Clone)]
 pub struct RollingOptionsFixedWindow {
@@ -26,6 +27,29 @@ impl Default for RollingOptionsFixedWindow {
     }
 }
 
+impl RollingOptionsFixedWindow {
+    /// Check that these options describe a valid window, before any values are computed.
+    pub fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.window_size > 0,
+            ComputeError: "`window_size` should be strictly positive"
+        );
+        polars_ensure!(
+            self.min_periods <= self.window_size,
+            ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
+            self.window_size, self.min_periods
+        );
+        if let Some(weights) = &self.weights {
+            polars_ensure!(
+                weights.len() == self.window_size,
+                ComputeError: "`weights` should have the same length as `window_size`: {}, got {}",
+                self.window_size, weights.len()
+            );
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "rolling_window")]
 mod inner_mod {
     use std::ops::SubAssign;
@@ -40,16 +64,6 @@ mod inner_mod {
 
     use crate::prelude::*;
 
-    /// utility
-    fn check_input(window_size: usize, min_periods: usize) -> PolarsResult<()> {
-        polars_ensure!(
-            min_periods <= window_size,
-            ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
-            window_size, min_periods
-        );
-        Ok(())
-    }
-
     /// utility
     fn window_edges(idx: usize, len: usize, window_size: usize, center: bool) -> (usize, usize) {
         let (start, end) = if center {
@@ -76,7 +90,7 @@ mod inner_mod {
             f: &dyn Fn(&Series) -> Series,
             mut options: RollingOptionsFixedWindow,
         ) -> PolarsResult<Series> {
-            check_input(options.window_size, options.min_periods)?;
+            options.validate()?;
 
             let ca = self.rechunk();
             if options.weights.is_some()
@@ -192,6 +206,10 @@ mod inner_mod {
         where
             F: FnMut(&mut ChunkedArray<T>) -> Option<T::Native>,
         {
+            polars_ensure!(
+                window_size > 0,
+                ComputeError: "`window_size` should be strictly positive"
+            );
             if window_size > self.len() {
                 return Ok(Self::full_null(self.name(), self.len()));
             }
@@ -241,12 +259,11 @@ mod inner_mod {
                     },
                 }
             }
-            let arr = PrimitiveArray::new(
-                T::get_dtype().to_arrow(),
-                values.into(),
+            Ok(Self::from_vec_validity(
+                self.name(),
+                values,
                 Some(validity.into()),
-            );
-            Ok(Self::with_chunk(self.name(), arr))
+            ))
         }
     }
 }
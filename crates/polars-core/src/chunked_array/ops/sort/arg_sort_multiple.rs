@@ -111,15 +111,23 @@ pub fn _get_rows_encoded(
     nulls_last: bool,
 ) -> PolarsResult<RowsEncoded> {
     debug_assert_eq!(by.len(), descending.len());
-    let mut cols = Vec::with_capacity(by.len());
-    let mut fields = Vec::with_capacity(by.len());
-    for (by, descending) in by.iter().zip(descending) {
+    let fields = descending.iter().map(|descending| SortField {
+        descending: *descending,
+        nulls_last,
+    });
+    _get_rows_encoded_with_fields(by.iter().zip(fields))
+}
+
+/// Row-encode the columns with a [`SortField`] per column.
+pub(crate) fn _get_rows_encoded_with_fields<'a, I>(by: I) -> PolarsResult<RowsEncoded>
+where
+    I: IntoIterator<Item = (&'a Series, SortField)>,
+{
+    let mut cols = Vec::new();
+    let mut fields = Vec::new();
+    for (by, sort_field) in by {
         let arr = _get_rows_encoded_compat_array(by)?;
 
-        let sort_field = SortField {
-            descending: *descending,
-            nulls_last,
-        };
         match arr.data_type() {
             // Flatten the struct fields.
             ArrowDataType::Struct(_) => {
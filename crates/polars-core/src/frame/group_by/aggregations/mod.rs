@@ -14,7 +14,6 @@ use arrow::legacy::kernels::rolling::no_nulls::{
 use arrow::legacy::kernels::rolling::nulls::RollingAggWindowNulls;
 use arrow::legacy::kernels::rolling::{RollingQuantileParams, RollingVarParams};
 use arrow::legacy::kernels::take_agg::*;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 use arrow::legacy::trusted_len::TrustedLenPush;
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
@@ -30,7 +29,7 @@ use crate::chunked_array::object::extension::create_extension;
 use crate::frame::group_by::GroupsIdx;
 #[cfg(feature = "object")]
 use crate::frame::group_by::GroupsIndicator;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 use crate::series::implementations::SeriesWrap;
 use crate::series::IsSorted;
 use crate::utils::NoNull;
@@ -2,14 +2,13 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 
 use ahash::RandomState;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 use num_traits::NumCast;
 use polars_utils::hashing::{BytesHash, DirtyHash};
 use rayon::prelude::*;
 
 use self::hashing::*;
 use crate::hashing::get_null_hash_value;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 use crate::utils::{_set_partition_size, accumulate_dataframes_vertical};
 use crate::POOL;
 
@@ -592,7 +591,7 @@ impl<'df> GroupBy<'df> {
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
-    /// # use arrow::legacy::prelude::QuantileInterpolOptions;
+    /// # use polars_core::prelude::QuantileInterpolOptions;
     ///
     /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
     ///     df.group_by(["date"])?.select(["temp"]).quantile(0.2, QuantileInterpolOptions::default())
@@ -4,12 +4,11 @@ use std::iter::{FromIterator, Iterator};
 use std::{mem, ops};
 
 use ahash::AHashSet;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 use rayon::prelude::*;
 
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 #[cfg(feature = "describe")]
 use crate::utils::concat_df_unchecked;
 use crate::utils::{slice_offsets, split_ca, split_df, try_get_supertype, NoNull};
@@ -21,6 +20,8 @@ pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
+#[cfg(feature = "rolling_window")]
+mod rolling;
 #[cfg(feature = "rows")]
 pub mod row;
 mod top_k;
@@ -411,6 +412,35 @@ impl DataFrame {
         DataFrame { columns }
     }
 
+    /// Create a new `DataFrame`, broadcasting unit-length `Series` to the length of the others.
+    ///
+    /// All `Series` that don't have length 1 must have the same length. Like
+    /// [`DataFrame::new_no_checks`], this does not check for duplicate names, so it can be used
+    /// to assemble temporary frames from expression inputs.
+    pub fn new_with_broadcast(columns: Vec<Series>) -> PolarsResult<DataFrame> {
+        let mut height = None;
+        for s in &columns {
+            match (height, s.len()) {
+                (_, 1) => {},
+                (None, len) => height = Some(len),
+                (Some(height), len) => polars_ensure!(
+                    height == len,
+                    ShapeMismatch: "could not create a new DataFrame: series {:?} has length {} \
+                    while the other series have length {}",
+                    s.name(), len, height
+                ),
+            }
+        }
+        let columns = match height {
+            Some(height) => columns
+                .iter()
+                .map(|s| s.broadcast_to(height))
+                .collect::<PolarsResult<_>>()?,
+            None => columns,
+        };
+        Ok(DataFrame { columns })
+    }
+
     /// Aggregate all chunks to contiguous memory.
     #[must_use]
     pub fn agg_chunks(&self) -> Self {
@@ -3467,6 +3497,25 @@ mod test {
         assert_eq!(df.column("days").unwrap().equal(1).unwrap().sum(), Some(1));
     }
 
+    #[test]
+    fn test_new_with_broadcast() {
+        let s0 = Series::new("days", [0, 1, 2].as_ref());
+        let s1 = Series::new("temp", [22.1].as_ref());
+        let df = DataFrame::new_with_broadcast(vec![s1.clone(), s0]).unwrap();
+        assert_eq!(df.shape(), (3, 2));
+        assert_eq!(
+            Vec::from(df.column("temp").unwrap().f64().unwrap()),
+            &[Some(22.1), Some(22.1), Some(22.1)]
+        );
+
+        let df = DataFrame::new_with_broadcast(vec![s1.clone(), s1.clone()]).unwrap();
+        assert_eq!(df.shape(), (1, 2));
+
+        let s2 = Series::new("wind", [1, 2].as_ref());
+        let s0 = Series::new("days", [0, 1, 2].as_ref());
+        assert!(DataFrame::new_with_broadcast(vec![s0, s1, s2]).is_err());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_filter_broadcast_on_utf8_col() {
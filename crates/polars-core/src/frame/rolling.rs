@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+impl DataFrame {
+    /// Apply `f` to every window of `window_size` consecutive rows and collect the outputs
+    /// as the rows of a new [`DataFrame`].
+    ///
+    /// Windows trail their row, so the first `window_size - 1` windows hold fewer rows. The
+    /// windows are zero-copy slices of this [`DataFrame`], which lets `f` compute statistics
+    /// over several columns at once (e.g. a covariance matrix) in a single pass.
+    ///
+    /// Every output of `f` must have the same length and dtype. A unit-length output gives a
+    /// single column with the name of the output, longer outputs give a column per value,
+    /// suffixed with their index.
+    pub fn rolling_apply_multi<F>(&self, window_size: usize, f: F) -> PolarsResult<DataFrame>
+    where
+        F: Fn(&DataFrame) -> PolarsResult<Series>,
+    {
+        polars_ensure!(window_size > 0, ComputeError: "`window_size` must be positive");
+        let height = self.height();
+        if height == 0 {
+            return Ok(DataFrame::empty());
+        }
+        // Slices of single-chunk columns don't have to look up the chunks of every window.
+        let mut df = self.clone();
+        df.as_single_chunk_par();
+
+        let mut out = f(&df.slice(0, 1))?;
+        let width = out.len();
+        for end in 2..=height {
+            let start = end.saturating_sub(window_size);
+            let s = f(&df.slice(start as i64, end - start))?;
+            polars_ensure!(
+                s.len() == width,
+                ShapeMismatch: "rolling function returned {} values, expected {}", s.len(), width
+            );
+            out.append(&s)?;
+        }
+        if width == 1 {
+            return DataFrame::new(vec![out.rechunk()]);
+        }
+
+        let name = out.name().to_string();
+        let columns = (0..width)
+            .map(|i| {
+                let idx = (0..height)
+                    .map(|row| (row * width + i) as IdxSize)
+                    .collect::<Vec<_>>();
+                let mut s = out.take_slice(&idx)?;
+                s.rename(&format!("{name}_{i}"));
+                Ok(s)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_apply_multi() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 4.0],
+            "b" => [2.0, 0.0, 1.0, 3.0],
+        ]?;
+
+        // The sum of the products of both columns in the window.
+        let out = df.rolling_apply_multi(2, |window| {
+            let s = window.column("a")? * window.column("b")?;
+            Ok(Series::new("dot", [s.sum::<f64>()]))
+        })?;
+        assert_eq!(out.get_column_names(), &["dot"]);
+        assert_eq!(
+            Vec::from(out.column("dot")?.f64()?),
+            &[Some(2.0), Some(2.0), Some(3.0), Some(15.0)]
+        );
+
+        // The minimum and maximum of both columns in the window.
+        let out = df.rolling_apply_multi(3, |window| {
+            let a = window.column("a")?;
+            let b = window.column("b")?;
+            Ok(Series::new(
+                "range",
+                [
+                    a.min::<f64>().unwrap().min(b.min::<f64>().unwrap()),
+                    a.max::<f64>().unwrap().max(b.max::<f64>().unwrap()),
+                ],
+            ))
+        })?;
+        assert_eq!(out.get_column_names(), &["range_0", "range_1"]);
+        assert_eq!(
+            Vec::from(out.column("range_0")?.f64()?),
+            &[Some(1.0), Some(0.0), Some(0.0), Some(0.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("range_1")?.f64()?),
+            &[Some(2.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+
+        assert!(df
+            .rolling_apply_multi(2, |window| Ok(window.column("a")?.clone()))
+            .is_err());
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
 use std::convert::TryFrom;
 
 use arrow::compute::cast::utf8_to_large_utf8;
+use arrow::compute::utils::combine_validities_and;
 use arrow::legacy::compute::cast::cast;
 #[cfg(any(feature = "dtype-struct", feature = "dtype-categorical"))]
 use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
@@ -126,6 +128,54 @@ impl Series {
         }
     }
 
+    /// Create a new [`Series`] from the values of `values` and the null mask `validity`.
+    ///
+    /// Positions where `validity` is `false` or null become null, existing nulls in `values`
+    /// are kept. If the chunks of both inputs line up, the bitmap of `validity` is reused
+    /// without copying.
+    pub fn from_chunks_validity(values: &Series, validity: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            values.len() == validity.len(),
+            ShapeMismatch: "validity mask has length {} while the values have length {}",
+            validity.len(), values.len()
+        );
+        match values.dtype() {
+            DataType::Null => return Ok(values.clone()),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => {
+                polars_bail!(InvalidOperation: "cannot set the validity of a struct series")
+            },
+            #[cfg(feature = "object")]
+            DataType::Object(_) => {
+                polars_bail!(InvalidOperation: "cannot set the validity of an object series")
+            },
+            _ => {},
+        }
+
+        let validity = if values.chunk_lengths().eq(validity.chunk_id()) {
+            Cow::Borrowed(validity)
+        } else {
+            Cow::Owned(validity.match_chunks(values.chunk_lengths()))
+        };
+        let chunks = values
+            .chunks()
+            .iter()
+            .zip(validity.downcast_iter())
+            .map(|(arr, mask)| {
+                // A null in the mask counts as invalid.
+                let mask = match mask.validity() {
+                    Some(mask_validity) => mask.values() & mask_validity,
+                    None => mask.values().clone(),
+                };
+                arr.with_validity(combine_validities_and(arr.validity(), Some(&mask)))
+            })
+            .collect();
+        // SAFETY: the chunks keep their physical type, only the validity changed.
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(values.name(), chunks, values.dtype())
+        })
+    }
+
     /// Create a new Series without checking if the inner dtype of the chunks is correct
     ///
     /// # Safety
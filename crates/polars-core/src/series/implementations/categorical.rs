@@ -1,7 +1,6 @@
 use std::borrow::Cow;
 
 use ahash::RandomState;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 
 use super::{private, IntoSeries, SeriesTrait, *};
 use crate::chunked_array::comparison::*;
@@ -10,7 +9,7 @@ use crate::chunked_array::ops::explode::ExplodeByOffsets;
 use crate::chunked_array::AsSinglePtr;
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::*;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 use crate::series::implementations::SeriesWrap;
 
 unsafe impl IntoSeries for CategoricalChunked {
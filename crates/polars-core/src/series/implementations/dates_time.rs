@@ -11,7 +11,6 @@ use std::borrow::Cow;
 use std::ops::Deref;
 
 use ahash::RandomState;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 
 use super::{private, IntoSeries, SeriesTrait, SeriesWrap, *};
 use crate::chunked_array::ops::explode::ExplodeByOffsets;
@@ -19,7 +18,7 @@ use crate::chunked_array::ops::ToBitRepr;
 use crate::chunked_array::AsSinglePtr;
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::*;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 
 macro_rules! impl_dyn_series {
     ($ca: ident, $into_logical: ident) => {
@@ -31,7 +31,6 @@ use std::borrow::Cow;
 use std::ops::{BitAnd, BitOr, BitXor, Deref};
 
 use ahash::RandomState;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 
 use super::{private, IntoSeries, SeriesTrait, *};
 use crate::chunked_array::comparison::*;
@@ -45,7 +44,7 @@ use crate::chunked_array::ops::take::TakeChunked;
 use crate::chunked_array::AsSinglePtr;
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::*;
-use crate::prelude::*;
+use crate::prelude::{QuantileInterpolOptions, *};
 #[cfg(feature = "checked_arithmetic")]
 use crate::series::arithmetic::checked::NumOpsDispatchChecked;
 
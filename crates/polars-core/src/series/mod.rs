@@ -19,18 +19,21 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use ahash::RandomState;
+use arrow::bitmap::Bitmap;
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
 use num_traits::NumCast;
+use polars_row::SortField;
 use rayon::prelude::*;
 pub use series_trait::{IsSorted, *};
 
+use crate::chunked_array::ops::sort::arg_sort_multiple::_get_rows_encoded_with_fields;
 use crate::chunked_array::Settings;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
-use crate::utils::{_split_offsets, handle_casting_failures, split_ca, split_series, Wrap};
+use crate::utils::{_split_offsets, handle_casting_failures, split_ca, split_series, NoNull, Wrap};
 use crate::POOL;
 
 /// # Series
@@ -157,6 +160,47 @@ impl Series {
         Series::full_null(name, 0, dtype)
     }
 
+    /// Create a [`Series`] of `len` values where the value at index `i` is `f(i)`.
+    ///
+    /// The values are written directly into a pre-allocated buffer, in order of their index.
+    pub fn generate<T, F>(name: &str, len: usize, f: F) -> Series
+    where
+        T: PolarsNumericType,
+        F: FnMut(usize) -> T::Native,
+        ChunkedArray<T>: IntoSeries,
+    {
+        let mut ca = (0..len).map(f).collect_trusted::<NoNull<ChunkedArray<T>>>();
+        ca.rename(name);
+        ca.into_inner().into_series()
+    }
+
+    /// Create a [`Series`] of `len` values where the value at index `i` is `f(i)`, or null if
+    /// `f(i)` returns `None`. See [`Series::generate`].
+    pub fn generate_opt<T, F>(name: &str, len: usize, f: F) -> Series
+    where
+        T: PolarsNumericType,
+        F: FnMut(usize) -> Option<T::Native>,
+        ChunkedArray<T>: IntoSeries,
+    {
+        let mut ca: ChunkedArray<T> = (0..len).map(f).collect_trusted();
+        ca.rename(name);
+        ca.into_series()
+    }
+
+    /// Create a [`Series`] by taking ownership of `values`, with the nulls given by `validity`.
+    /// See [`ChunkedArray::from_vec_validity`].
+    pub fn from_vec_validity<T>(
+        name: &str,
+        values: Vec<T::Native>,
+        validity: Option<Bitmap>,
+    ) -> Series
+    where
+        T: PolarsNumericType,
+        ChunkedArray<T>: IntoSeries,
+    {
+        ChunkedArray::<T>::from_vec_validity(name, values, validity).into_series()
+    }
+
     pub fn clear(&self) -> Series {
         // Only the inner of objects know their type, so use this hack.
         #[cfg(feature = "object")]
@@ -171,6 +215,46 @@ impl Series {
         Series::new_empty(self.name(), self.dtype())
     }
 
+    /// Broadcast this [`Series`] to `len` values.
+    ///
+    /// A unit-length [`Series`] repeats its value, a null value becomes an all-null [`Series`]
+    /// of the same dtype. A [`Series`] that already has length `len` is returned as is, any
+    /// other length is a [`PolarsError::ShapeMismatch`].
+    ///
+    /// A null is broadcast by building a single all-null chunk with a zeroed validity, rather
+    /// than gathering the null `len` times.
+    pub fn broadcast_to(&self, len: usize) -> PolarsResult<Series> {
+        // Only the inner of objects know their type, so they can't create a null series.
+        #[cfg(feature = "object")]
+        let is_object = matches!(self.dtype(), DataType::Object(_));
+        #[cfg(not(feature = "object"))]
+        let is_object = false;
+
+        match self.len() {
+            n if n == len => Ok(self.clone()),
+            1 if self.null_count() == 1 && !is_object => {
+                Ok(Series::full_null(self.name(), len, self.dtype()))
+            },
+            1 => Ok(self.new_from_index(0, len)),
+            n => polars_bail!(
+                ShapeMismatch: "cannot broadcast a series of length {} to length {}", n, len
+            ),
+        }
+    }
+
+    /// Get the indices of the rows where `condition` is `true`; nulls count as `false`.
+    ///
+    /// This is [`BooleanChunked::arg_true`] after checking that `condition` has the length of
+    /// this [`Series`]. The indices are named after this [`Series`].
+    pub fn arg_where(&self, condition: &BooleanChunked) -> PolarsResult<IdxCa> {
+        polars_ensure!(
+            condition.len() == self.len(),
+            ShapeMismatch: "`condition` must have the same length as the series, got {} and {}",
+            condition.len(), self.len()
+        );
+        Ok(condition.arg_true().with_name(self.name()))
+    }
+
     #[doc(hidden)]
     pub fn _get_inner_mut(&mut self) -> &mut dyn SeriesTrait {
         if Arc::weak_count(&self.0) + Arc::strong_count(&self.0) != 1 {
@@ -289,6 +373,35 @@ impl Series {
         })
     }
 
+    /// Encode multiple columns into a single binary sort key.
+    ///
+    /// The keys use an order-preserving row encoding, so comparing them byte-wise gives the
+    /// same order as comparing the columns lexicographically with their [`SortOptions`].
+    /// The output takes the name of the first column.
+    pub fn composite_sort_key(columns: &[(&Series, SortOptions)]) -> PolarsResult<BinaryChunked> {
+        let Some((first, _)) = columns.first() else {
+            polars_bail!(ComputeError: "cannot create a sort key from zero columns");
+        };
+        for (s, _) in columns {
+            polars_ensure!(
+                s.len() == first.len(),
+                ShapeMismatch: "sort key columns must have equal length, got {} and {}",
+                first.len(), s.len()
+            );
+        }
+        let fields = columns.iter().map(|(s, options)| {
+            (
+                *s,
+                SortField {
+                    descending: options.descending,
+                    nulls_last: options.nulls_last,
+                },
+            )
+        });
+        let rows = _get_rows_encoded_with_fields(fields)?;
+        Ok(BinaryChunked::with_chunk(first.name(), rows.into_array()))
+    }
+
     /// Only implemented for numeric types
     pub fn as_single_ptr(&mut self) -> PolarsResult<usize> {
         self._get_inner_mut().as_single_ptr()
@@ -476,6 +589,59 @@ impl Series {
         }
     }
 
+    /// Owned version of [`Series::to_physical_repr`].
+    pub fn to_physical(&self) -> Series {
+        self.to_physical_repr().into_owned()
+    }
+
+    /// Reconstruct a [`Series`] of the logical type `dtype` from its physical representation.
+    ///
+    /// This is the inverse of [`Series::to_physical`]. The dtype of `physical` must be the
+    /// physical type of `dtype`. Date, datetime, duration and time series reuse the buffers of
+    /// `physical`.
+    pub fn from_physical(physical: Series, dtype: DataType) -> PolarsResult<Series> {
+        polars_ensure!(
+            physical.dtype() == &dtype.to_physical(),
+            SchemaMismatch: "series of dtype {} is not the physical representation of {}",
+            physical.dtype(), dtype
+        );
+        use DataType::*;
+        match &dtype {
+            dt if !dt.is_logical() => Ok(physical),
+            Date | Datetime(_, _) | Duration(_) | Time => {
+                // SAFETY: we checked that the physical type matches.
+                Ok(unsafe {
+                    Series::from_chunks_and_dtype_unchecked(
+                        physical.name(),
+                        physical.chunks().clone(),
+                        &dtype,
+                    )
+                })
+            },
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(rev_map) => {
+                let Some(rev_map) = rev_map else {
+                    polars_bail!(ComputeError: "cannot create a categorical series without a categorical mapping")
+                };
+                let cats = physical.u32().unwrap();
+                polars_ensure!(
+                    cats.into_iter()
+                        .all(|opt_cat| opt_cat.map_or(true, |cat| rev_map.get_optional(cat).is_some())),
+                    ComputeError: "physical values are not valid categories of the given dtype"
+                );
+                // SAFETY: we checked that all categories are in the rev map.
+                Ok(unsafe {
+                    Series::from_chunks_and_dtype_unchecked(
+                        physical.name(),
+                        physical.chunks().clone(),
+                        &dtype,
+                    )
+                })
+            },
+            _ => physical.cast(&dtype),
+        }
+    }
+
     fn finish_take_threaded(&self, s: Vec<Series>, rechunk: bool) -> Series {
         let s = s
             .into_iter()
@@ -686,6 +852,33 @@ impl Series {
         Ok(s)
     }
 
+    /// Cast the argument `arg_name` of `function_name` to `dtype`, with [`Series::strict_cast`]
+    /// if `strict` is set and with [`Series::cast`] otherwise.
+    ///
+    /// Errors name the argument and the function. A strict cast that turns values into nulls
+    /// lists some of the values that failed to convert.
+    pub fn cast_with_context(
+        &self,
+        dtype: &DataType,
+        arg_name: &str,
+        function_name: &str,
+        strict: bool,
+    ) -> PolarsResult<Series> {
+        let out = if strict {
+            self.strict_cast(dtype)
+        } else {
+            self.cast(dtype)
+        };
+        out.map_err(|err| {
+            err.wrap_msg(&|msg| {
+                format!(
+                    "cannot cast argument `{arg_name}` of `{function_name}` from {} to {dtype}: {msg}",
+                    self.dtype()
+                )
+            })
+        })
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn into_time(self) -> Series {
         #[cfg(not(feature = "dtype-time"))]
@@ -983,6 +1176,156 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    fn generate() {
+        let s = Series::generate::<Float64Type, _>("sq", 4, |i| (i * i) as f64);
+        assert_eq!(s.name(), "sq");
+        assert_eq!(
+            Vec::from(s.f64().unwrap()),
+            &[Some(0.0), Some(1.0), Some(4.0), Some(9.0)]
+        );
+        assert_eq!(s.null_count(), 0);
+
+        let s =
+            Series::generate_opt::<Int32Type, _>("odd", 4, |i| (i % 2 == 1).then_some(i as i32));
+        assert_eq!(Vec::from(s.i32().unwrap()), &[None, Some(1), None, Some(3)]);
+
+        let s = Series::generate::<UInt8Type, _>("empty", 0, |_| unreachable!());
+        assert_eq!(s.dtype(), &DataType::UInt8);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn arg_where() {
+        let s = Series::new("a", &[Some(1), None, Some(3), Some(4)]);
+        let idx = s.arg_where(&s.is_null()).unwrap();
+        assert_eq!(idx.name(), "a");
+        assert_eq!(Vec::from(&idx), &[Some(1)]);
+
+        let condition = BooleanChunked::new("", &[Some(true), Some(false), None, Some(true)]);
+        let idx = s.arg_where(&condition).unwrap();
+        assert_eq!(Vec::from(&idx), &[Some(0), Some(3)]);
+
+        assert!(s.arg_where(&condition.slice(0, 3)).is_err());
+    }
+
+    #[test]
+    fn from_vec_validity() {
+        let values = vec![1i64, 2, 3, 4];
+        let ptr = values.as_ptr();
+        let validity = Bitmap::from([false, true, true, false]);
+        let s = Series::from_vec_validity::<Int64Type>("a", values, Some(validity));
+        assert_eq!(s.null_count(), 2);
+        assert_eq!(Vec::from(s.i64().unwrap()), &[None, Some(2), Some(3), None]);
+        // The values are not copied.
+        let arr = s.i64().unwrap().downcast_iter().next().unwrap();
+        assert_eq!(arr.values().as_ptr(), ptr);
+
+        let s = Series::from_vec_validity::<Float32Type>("b", vec![1.0, 2.0], None);
+        assert_eq!(s.null_count(), 0);
+    }
+
+    #[test]
+    fn composite_sort_key() {
+        let a = Series::new("a", &[Some(2i32), Some(1), None, Some(2), Some(-3)]);
+        let b = Series::new("b", &["x", "y", "z", "abc", "x"]);
+        let asc = SortOptions::default();
+        let desc = SortOptions {
+            descending: true,
+            nulls_last: true,
+            ..Default::default()
+        };
+
+        let check = |options_a: SortOptions, options_b: SortOptions, expected: &[IdxSize]| {
+            let keys = Series::composite_sort_key(&[(&a, options_a), (&b, options_b)]).unwrap();
+            assert_eq!(keys.name(), "a");
+            let mut idx = (0..keys.len() as IdxSize).collect::<Vec<_>>();
+            idx.sort_by_key(|i| keys.get(*i as usize));
+            assert_eq!(idx, expected);
+        };
+        check(asc, asc, &[2, 4, 1, 3, 0]);
+        check(desc, asc, &[3, 0, 1, 4, 2]);
+        check(asc, desc, &[2, 4, 1, 0, 3]);
+
+        let short = Series::new("c", &[1i32]);
+        assert!(Series::composite_sort_key(&[(&a, asc), (&short, asc)]).is_err());
+        assert!(Series::composite_sort_key(&[]).is_err());
+    }
+
+    #[test]
+    fn broadcast_to() {
+        let s = Series::new("a", &[7i32]);
+        let out = s.broadcast_to(3).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(7), Some(7), Some(7)]);
+
+        let s = Series::new("a", &[None::<i32>]);
+        let out = s.broadcast_to(2).unwrap();
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(out.null_count(), 2);
+
+        let s = Series::new("a", &[1i32, 2]);
+        assert_eq!(s.broadcast_to(2).unwrap().len(), 2);
+        assert!(matches!(
+            s.broadcast_to(3),
+            Err(PolarsError::ShapeMismatch(_))
+        ));
+        assert!(s.broadcast_to(1).is_err());
+
+        let s = Series::new_empty("a", &DataType::Int32);
+        assert_eq!(s.broadcast_to(0).unwrap().len(), 0);
+        assert!(s.broadcast_to(2).is_err());
+    }
+
+    #[test]
+    fn broadcast_null() {
+        let n = 1_000_000;
+        for (s, dtype) in [
+            (Series::new("a", &[None::<i64>]), DataType::Int64),
+            (Series::new("b", &[None::<&str>]), DataType::Utf8),
+        ] {
+            let out = s.broadcast_to(n).unwrap();
+            assert_eq!(out.dtype(), &dtype);
+            assert_eq!(out.chunks().len(), 1);
+            // A single zeroed validity of `n` bits.
+            let validity = out.chunks()[0].validity().unwrap();
+            assert_eq!(validity.unset_bits(), n);
+            assert_eq!(validity.as_slice().0.len(), (n + 7) / 8);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn to_from_physical() {
+        let s = Series::new("a", &[Some(1i64), None, Some(3)]);
+        let dtype = DataType::Datetime(TimeUnit::Milliseconds, None);
+        let dt = s.cast(&dtype).unwrap();
+
+        let physical = dt.to_physical();
+        assert_eq!(physical.dtype(), &DataType::Int64);
+        let out = Series::from_physical(physical, dtype.clone()).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.equals_missing(&dt));
+
+        let s = Series::new("a", &[1i32, 2]);
+        assert!(Series::from_physical(s, dtype).is_err());
+    }
+
+    #[test]
+    fn from_chunks_validity() {
+        let mut values = Series::new("a", &[Some(1i32), None, Some(3)]);
+        values.append(&Series::new("a", &[4i32])).unwrap();
+        let validity = BooleanChunked::new("", &[Some(true), Some(true), Some(false), None]);
+
+        let out = Series::from_chunks_validity(&values, &validity).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.n_chunks(), 2);
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), None, None, None]);
+
+        let validity = BooleanChunked::new("", &[true, false]);
+        assert!(Series::from_chunks_validity(&values, &validity).is_err());
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series", &vec![true, false, true]);
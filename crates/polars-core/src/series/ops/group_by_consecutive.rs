@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+/// Iterator over the runs of equal consecutive values of a [`Series`].
+///
+/// Created by [`Series::group_by_consecutive`].
+pub struct GroupByConsecutiveIter<'a> {
+    s: &'a Series,
+    // The first row of every run.
+    starts: Vec<IdxSize>,
+    i: usize,
+}
+
+impl<'a> Iterator for GroupByConsecutiveIter<'a> {
+    type Item = (AnyValue<'a>, Series);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.starts.get(self.i)?;
+        self.i += 1;
+        let end = self
+            .starts
+            .get(self.i)
+            .copied()
+            .unwrap_or(self.s.len() as IdxSize);
+        // SAFETY: the first row of a run is within bounds.
+        let key = unsafe { self.s.get_unchecked(start as usize) };
+        let idx = IdxCa::from_vec(self.s.name(), (start..end).collect());
+        Some((key, idx.into_series()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.starts.len() - self.i;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for GroupByConsecutiveIter<'_> {}
+
+impl Series {
+    /// Group the runs of equal consecutive values of this [`Series`] and iterate over the
+    /// `(value, row indices)` pairs, in order.
+    ///
+    /// Unlike [`Series::group_by_value`], a value that appears in several runs forms a group
+    /// per run. Consecutive nulls form a run, NaN is never equal to the previous value.
+    pub fn group_by_consecutive(&self) -> PolarsResult<GroupByConsecutiveIter<'_>> {
+        let len = self.len();
+        let mut starts = vec![];
+        if len > 0 {
+            starts.push(0);
+            let changed = self
+                .slice(0, len - 1)
+                .not_equal_missing(&self.slice(1, len - 1))?;
+            starts.extend(
+                changed
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, changed)| *changed == Some(true))
+                    .map(|(i, _)| (i + 1) as IdxSize),
+            );
+        }
+        Ok(GroupByConsecutiveIter {
+            s: self,
+            starts,
+            i: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_by_consecutive() -> PolarsResult<()> {
+        let s = Series::new("a", &["A", "A", "B", "B", "A"]);
+        let groups = s
+            .group_by_consecutive()?
+            .map(|(key, idx)| {
+                let idx = idx.idx().unwrap().into_no_null_iter().collect::<Vec<_>>();
+                (key.into_static().unwrap(), idx)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            &[
+                (AnyValue::Utf8Owned("A".into()), vec![0, 1]),
+                (AnyValue::Utf8Owned("B".into()), vec![2, 3]),
+                (AnyValue::Utf8Owned("A".into()), vec![4]),
+            ]
+        );
+
+        let s = Series::new("a", &[None, None, Some(1), None]);
+        let lengths = s
+            .group_by_consecutive()?
+            .map(|(_, idx)| idx.len())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, &[2, 1, 1]);
+
+        let s = Series::new_empty("a", &DataType::Int32);
+        assert_eq!(s.group_by_consecutive()?.len(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use polars_utils::idx_vec::IdxVec;
+
+use crate::prelude::*;
+
+/// Iterator over the groups of equal values of a [`Series`].
+///
+/// Created by [`Series::group_by_value`].
+pub struct GroupByValueIter<'a> {
+    s: &'a Series,
+    groups: std::vec::IntoIter<(IdxSize, IdxVec)>,
+}
+
+impl<'a> Iterator for GroupByValueIter<'a> {
+    type Item = (AnyValue<'a>, IdxVec);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, idx) = self.groups.next()?;
+        // SAFETY: the first index of a group is within bounds.
+        let key = unsafe { self.s.get_unchecked(first as usize) };
+        Some((key, idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
+
+impl ExactSizeIterator for GroupByValueIter<'_> {}
+
+impl Series {
+    /// Group the values of this [`Series`] and iterate over the `(value, row indices)` pairs.
+    ///
+    /// Groups are yielded in the order in which their value first appears. Null values form
+    /// a group of their own.
+    pub fn group_by_value(&self) -> PolarsResult<GroupByValueIter<'_>> {
+        let groups = match self.group_tuples(true, true)? {
+            GroupsProxy::Idx(groups) => groups.into_iter().collect::<Vec<_>>(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .into_iter()
+                .map(|[first, len]| (first, (first..first + len).collect::<IdxVec>()))
+                .collect(),
+        };
+        Ok(GroupByValueIter {
+            s: self,
+            groups: groups.into_iter(),
+        })
+    }
+
+    /// Group the values of this [`Series`] by the values of `key` and collect every group
+    /// in a list.
+    ///
+    /// The lists are in the order in which their key first appears; null keys form a list
+    /// of their own. This equals `group_by(key).agg(col(value))` without building a
+    /// [`DataFrame`].
+    pub fn to_list_chunked_by(&self, key: &Series) -> PolarsResult<ListChunked> {
+        polars_ensure!(
+            self.len() == key.len(),
+            ShapeMismatch: "`key` must have the same length as the series, got {} and {}",
+            key.len(), self.len()
+        );
+        let groups = key.group_tuples(true, true)?;
+        // SAFETY: `key` has the same length as `self`, so the groups are in bounds.
+        let out = unsafe { self.agg_list(&groups) };
+        Ok(out.list()?.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_by_value() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some("b"), Some("a"), None, Some("b"), Some("a"), None],
+        );
+        let groups = s
+            .group_by_value()?
+            .map(|(key, idx)| (key.into_static().unwrap(), idx.to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            &[
+                (AnyValue::Utf8Owned("b".into()), vec![0, 3]),
+                (AnyValue::Utf8Owned("a".into()), vec![1, 4]),
+                (AnyValue::Null, vec![2, 5]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_list_chunked_by() -> PolarsResult<()> {
+        let key = Series::new("key", &[Some("b"), Some("a"), None, Some("b"), Some("a")]);
+        let s = Series::new("v", &[1, 2, 3, 4, 5]);
+        let out = s.to_list_chunked_by(&key)?;
+        assert_eq!(out.name(), "v");
+        let out = out
+            .into_iter()
+            .map(|l| l.unwrap().i32().unwrap().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                vec![Some(1), Some(4)],
+                vec![Some(2), Some(5)],
+                vec![Some(3)]
+            ]
+        );
+
+        let empty = Series::new_empty("v", &DataType::Int32);
+        let empty_key = Series::new_empty("key", &DataType::Utf8);
+        assert_eq!(empty.to_list_chunked_by(&empty_key)?.len(), 0);
+        assert!(s.to_list_chunked_by(&key.slice(0, 2)).is_err());
+        Ok(())
+    }
+}
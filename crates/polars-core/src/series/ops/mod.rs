@@ -1,7 +1,15 @@
 mod downcast;
 mod extend;
+mod group_by_consecutive;
+#[cfg(feature = "algorithm_group_by")]
+mod group_by_value;
 mod null;
+#[cfg(feature = "rolling_window")]
+mod running_quantile;
 mod to_list;
+pub use group_by_consecutive::GroupByConsecutiveIter;
+#[cfg(feature = "algorithm_group_by")]
+pub use group_by_value::GroupByValueIter;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
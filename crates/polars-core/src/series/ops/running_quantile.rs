@@ -0,0 +1,86 @@
+use std::iter::Sum;
+use std::ops::{AddAssign, SubAssign};
+use std::sync::Arc;
+
+use arrow::legacy::kernels::rolling;
+use arrow::legacy::prelude::RollingQuantileParams;
+use num_traits::Float;
+use polars_utils::float::IsFloat;
+
+use crate::prelude::{QuantileInterpolOptions, *};
+
+fn running_quantile_ca<T>(
+    ca: &ChunkedArray<T>,
+    quantile: f64,
+    window: usize,
+) -> PolarsResult<Series>
+where
+    T: PolarsFloatType,
+    T::Native: Float + IsFloat + Sum + AddAssign + SubAssign,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let params = Some(Arc::new(RollingQuantileParams {
+        prob: quantile,
+        interpol: QuantileInterpolOptions::Linear,
+    }) as Arc<dyn std::any::Any + Send + Sync>);
+    let out = match arr.null_count() {
+        0 => rolling::no_nulls::rolling_quantile(
+            arr.values().as_slice(),
+            window,
+            1,
+            false,
+            None,
+            params,
+        )?,
+        _ => rolling::nulls::rolling_quantile(arr, window, 1, false, None, params),
+    };
+    Series::try_from((ca.name(), out))
+}
+
+impl Series {
+    /// Compute the linearly interpolated `quantile` of every `window` consecutive values,
+    /// ending at each row.
+    ///
+    /// The first rows use the values that are available, and nulls are skipped, so a row is
+    /// only null if its whole window is null. Float input keeps its dtype and integer input
+    /// gives `Float64`. For more control over the window, see `rolling_quantile` in
+    /// `polars-time`.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 5, 2, 8, 3]);
+    /// let out = s.running_quantile(0.5, 3)?;
+    /// assert_eq!(out.name(), "a");
+    /// assert_eq!(
+    ///     Vec::from(out.f64()?),
+    ///     &[Some(1.0), Some(3.0), Some(2.0), Some(5.0), Some(3.0)]
+    /// );
+    ///
+    /// assert!(s.running_quantile(1.5, 3).is_err());
+    /// assert!(s.running_quantile(0.5, 0).is_err());
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn running_quantile(&self, quantile: f64, window: usize) -> PolarsResult<Series> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "`quantile` should be between 0.0 and 1.0, got {}", quantile
+        );
+        polars_ensure!(window > 0, ComputeError: "`window` should be strictly positive");
+        polars_ensure!(
+            self.dtype().is_numeric(),
+            opq = running_quantile,
+            self.dtype()
+        );
+
+        let s = self.to_float()?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name(), s.dtype()));
+        }
+        match s.dtype() {
+            DataType::Float32 => running_quantile_ca(s.f32().unwrap(), quantile, window),
+            DataType::Float64 => running_quantile_ca(s.f64().unwrap(), quantile, window),
+            _ => unreachable!(),
+        }
+    }
+}
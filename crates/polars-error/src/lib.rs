@@ -62,6 +62,8 @@ pub enum PolarsError {
     NoData(ErrString),
     #[error("{0}")]
     OutOfBounds(ErrString),
+    #[error("out of memory: {0}")]
+    OutOfMemory(ErrString),
     #[error("field not found: {0}")]
     SchemaFieldNotFound(ErrString),
     #[error("data types don't match: {0}")]
@@ -137,6 +139,7 @@ impl PolarsError {
             Io(err) => ComputeError(func(&format!("IO: {err}")).into()),
             NoData(msg) => NoData(func(msg).into()),
             OutOfBounds(msg) => OutOfBounds(func(msg).into()),
+            OutOfMemory(msg) => OutOfMemory(func(msg).into()),
             SchemaFieldNotFound(msg) => SchemaFieldNotFound(func(msg).into()),
             SchemaMismatch(msg) => SchemaMismatch(func(msg).into()),
             ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
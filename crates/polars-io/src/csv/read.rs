@@ -281,7 +281,12 @@ where
     }
 
     /// Overwrite the dtypes in the schema in the order of the slice that's given.
-    /// This is useful if you don't know the column names beforehand
+    /// This is useful if you don't know the column names beforehand, e.g. when reading a
+    /// file without a header.
+    ///
+    /// Column `i` gets `dtypes[i]`. If the slice is shorter than the number of columns, the
+    /// remaining columns keep their inferred dtype. A slice longer than the number of columns
+    /// raises an error.
     pub fn with_dtypes_slice(mut self, dtypes: Option<&'a [DataType]>) -> Self {
         self.dtype_overwrite = dtypes;
         self
@@ -259,6 +259,11 @@ impl<'a> CoreReader<'a> {
             },
         };
         if let Some(dtypes) = dtype_overwrite {
+            polars_ensure!(
+                dtypes.len() <= schema.len(),
+                ComputeError: "the number of dtypes ({}) exceeds the number of columns ({})",
+                dtypes.len(), schema.len()
+            );
             let s = Arc::make_mut(&mut schema);
             for (index, dt) in dtypes.iter().enumerate() {
                 s.set_dtype_at_index(index, dt.clone()).unwrap();
@@ -131,8 +131,11 @@ where
     }
 
     /// Set the CSV file's null value representation.
-    pub fn with_null_value(mut self, null_value: String) -> Self {
-        self.options.null = null_value;
+    ///
+    /// Nulls are written as empty fields by default. Read the file back with a matching
+    /// [`NullValues`](crate::csv::NullValues) to recover the nulls.
+    pub fn with_null_value(mut self, null_value: impl Into<String>) -> Self {
+        self.options.null = null_value.into();
         self
     }
 
@@ -24,6 +24,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
     );
 
     let mut opt_state = lf.opt_state;
+    let memory_limit = lf.memory_limit;
     let options = UnionOptions {
         parallel,
         from_partitioned_ds,
@@ -62,12 +63,15 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
             };
             let mut lf = LazyFrame::from(lp);
             lf.opt_state = opt_state;
+            lf.memory_limit = memory_limit;
 
             lf
         },
     };
 
     if convert_supertypes {
+        let opt_state = lf.opt_state;
+        let memory_limit = lf.memory_limit;
         let LogicalPlan::Union {
             mut inputs,
             options,
@@ -109,7 +113,10 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
                 std::mem::swap(&mut placeholder, input);
             }
         }
-        Ok(LazyFrame::from(LogicalPlan::Union { inputs, options }))
+        let mut lf = LazyFrame::from(LogicalPlan::Union { inputs, options });
+        lf.opt_state = opt_state;
+        lf.memory_limit = memory_limit;
+        Ok(lf)
     } else {
         Ok(lf)
     }
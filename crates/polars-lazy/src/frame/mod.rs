@@ -17,7 +17,6 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 pub use anonymous_scan::*;
-use arrow::legacy::prelude::QuantileInterpolOptions;
 #[cfg(feature = "csv")]
 pub use csv::*;
 pub use file_list_reader::*;
@@ -28,7 +27,7 @@ pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_core::frame::explode::MeltArgs;
-use polars_core::prelude::*;
+use polars_core::prelude::{QuantileInterpolOptions, *};
 use polars_io::RowCount;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
@@ -45,7 +44,7 @@ use smartstring::alias::String as SmartString;
 
 use crate::fallible;
 use crate::physical_plan::executors::Executor;
-use crate::physical_plan::planner::{create_physical_expr, create_physical_plan};
+use crate::physical_plan::planner::{create_physical_expr, create_physical_plan_with_memory_limit};
 use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
@@ -62,6 +61,7 @@ impl IntoLazy for DataFrame {
         LazyFrame {
             logical_plan: lp,
             opt_state: Default::default(),
+            memory_limit: None,
         }
     }
 }
@@ -74,6 +74,7 @@ impl IntoLazy for DataFrame {
 pub struct LazyFrame {
     pub logical_plan: LogicalPlan,
     pub(crate) opt_state: OptState,
+    pub(crate) memory_limit: Option<usize>,
 }
 
 impl From<LogicalPlan> for LazyFrame {
@@ -84,6 +85,7 @@ impl From<LogicalPlan> for LazyFrame {
                 file_caching: true,
                 ..Default::default()
             },
+            memory_limit: None,
         }
     }
 }
@@ -98,6 +100,36 @@ impl LazyFrame {
         self.logical_plan.schema().map(|schema| schema.into_owned())
     }
 
+    /// Check that the schema of the current `LazyFrame` computation matches `schema`, without
+    /// executing the query.
+    ///
+    /// Both schemas must have the same column names and data types. If `strict` is set, the
+    /// columns must also be in the same order.
+    pub fn expect_schema(self, schema: &Schema, strict: bool) -> PolarsResult<LazyFrame> {
+        let current = self.schema()?;
+        polars_ensure!(
+            current.len() == schema.len(),
+            SchemaMismatch: "expected {} columns, found {}", schema.len(), current.len()
+        );
+        for (i, (name, dtype)) in schema.iter().enumerate() {
+            let found = if strict {
+                current.get_at_index(i).filter(|(found, _)| *found == name)
+            } else {
+                current
+                    .get_full(name)
+                    .map(|(_, found, dtype)| (found, dtype))
+            };
+            let Some((_, found_dtype)) = found else {
+                polars_bail!(SchemaMismatch: "expected column '{}' at position {}", name, i);
+            };
+            polars_ensure!(
+                found_dtype == dtype,
+                SchemaMismatch: "expected column '{}' to have dtype {}, found {}", name, dtype, found_dtype
+            );
+        }
+        Ok(self)
+    }
+
     pub(crate) fn get_plan_builder(self) -> LogicalPlanBuilder {
         LogicalPlanBuilder::from(self.logical_plan)
     }
@@ -106,10 +138,15 @@ impl LazyFrame {
         self.opt_state
     }
 
-    fn from_logical_plan(logical_plan: LogicalPlan, opt_state: OptState) -> Self {
+    fn from_logical_plan(
+        logical_plan: LogicalPlan,
+        opt_state: OptState,
+        memory_limit: Option<usize>,
+    ) -> Self {
         LazyFrame {
             logical_plan,
             opt_state,
+            memory_limit,
         }
     }
 
@@ -194,6 +231,23 @@ impl LazyFrame {
         self
     }
 
+    /// Fail the query with a [`PolarsError::OutOfMemory`] as soon as the estimated size
+    /// of an intermediate [`DataFrame`] exceeds `bytes`.
+    ///
+    /// The output of every node in the physical plan is checked after that node is
+    /// executed, using [`DataFrame::estimated_size`]. This bounds the size of the
+    /// intermediate results, not the peak memory of the process.
+    ///
+    /// The limit is not enforced inside the streaming engine: a part of the query that
+    /// runs streaming is checked only once, on the [`DataFrame`] it produces. Its
+    /// operators, sinks and spilled state are not checked.
+    ///
+    /// The limit is not an optimization and is kept by [`with_optimizations`](Self::with_optimizations).
+    pub fn limit_memory(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
@@ -254,6 +308,8 @@ impl LazyFrame {
         let maintain_order = options.maintain_order;
 
         let opt_state = self.get_opt_state();
+
+        let memory_limit = self.memory_limit;
         let lp = self
             .get_plan_builder()
             .sort(
@@ -263,7 +319,7 @@ impl LazyFrame {
                 maintain_order,
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Add a sort operation to the logical plan.
@@ -298,14 +354,23 @@ impl LazyFrame {
             self
         } else {
             let opt_state = self.get_opt_state();
+            let memory_limit = self.memory_limit;
             let lp = self
                 .get_plan_builder()
                 .sort(by_exprs, descending, nulls_last, maintain_order)
                 .build();
-            Self::from_logical_plan(lp, opt_state)
+            Self::from_logical_plan(lp, opt_state, memory_limit)
         }
     }
 
+    /// Sort the LazyFrame by a single expression, e.g. `col("name").str().len_chars()`.
+    ///
+    /// The expression is only evaluated as the sort key, so it is not added to the output.
+    /// See [`LazyFrame::sort_by_exprs`] to sort by several expressions.
+    pub fn sort_by_expr(self, expr: Expr, descending: bool, nulls_last: bool) -> Self {
+        self.sort_by_exprs([expr], [descending], nulls_last, false)
+    }
+
     pub fn top_k<E: AsRef<[Expr]>, B: AsRef<[bool]>>(
         self,
         k: IdxSize,
@@ -379,7 +444,11 @@ impl LazyFrame {
                 .get_plan_builder()
                 .add_err(polars_err!(SchemaFieldNotFound: "{}", name))
                 .build();
-            Some(Self::from_logical_plan(lp, self.opt_state))
+            Some(Self::from_logical_plan(
+                lp,
+                self.opt_state,
+                self.memory_limit,
+            ))
         } else {
             None
         }
@@ -443,8 +512,10 @@ impl LazyFrame {
             .collect::<PlHashSet<_>>();
 
         let opt_state = self.get_opt_state();
+
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().drop_columns(to_drop).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Shift the values by a given period and fill the parts that will be empty due to this operation
@@ -466,15 +537,17 @@ impl LazyFrame {
     /// Fill None values in the DataFrame with an expression.
     pub fn fill_null<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().fill_null(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Fill NaN values in the DataFrame with an expression.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().fill_nan(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Caches the result into a new LazyFrame.
@@ -482,8 +555,9 @@ impl LazyFrame {
     /// This should be used to prevent computations running multiple times.
     pub fn cache(self) -> Self {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().cache().build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Cast named frame columns, resulting in a new LazyFrame with updated dtypes
@@ -636,7 +710,12 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let physical_plan = create_physical_plan_with_memory_limit(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            self.memory_limit,
+        )?;
 
         let state = ExecutionState::with_finger_prints(finger_prints);
         Ok((state, physical_plan, no_file_sink))
@@ -834,8 +913,9 @@ impl LazyFrame {
     /// ```
     pub fn filter(self, predicate: Expr) -> Self {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().filter(predicate).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Select (and optionally rename, with [`alias`](crate::dsl::Expr::alias)) columns from the query.
@@ -887,8 +967,9 @@ impl LazyFrame {
 
     fn select_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> Self {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().project(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Performs a "group-by" on a `LazyFrame`, producing a [`LazyGroupBy`], which can subsequently be aggregated.
@@ -900,7 +981,7 @@ impl LazyFrame {
     /// ```rust
     /// use polars_core::prelude::*;
     /// use polars_lazy::prelude::*;
-    /// use arrow::legacy::prelude::QuantileInterpolOptions;
+    /// use polars_core::prelude::QuantileInterpolOptions;
     ///
     /// fn example(df: DataFrame) -> LazyFrame {
     ///       df.lazy()
@@ -919,12 +1000,14 @@ impl LazyFrame {
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
 
         #[cfg(feature = "dynamic_group_by")]
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                memory_limit,
                 keys,
                 maintain_order: false,
                 dynamic_options: None,
@@ -937,6 +1020,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                memory_limit,
                 keys,
                 maintain_order: false,
             }
@@ -970,9 +1054,11 @@ impl LazyFrame {
             );
         }
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
+            memory_limit,
             keys: by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: None,
@@ -1015,9 +1101,11 @@ impl LazyFrame {
             );
         }
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
+            memory_limit,
             keys: by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: Some(options),
@@ -1033,12 +1121,14 @@ impl LazyFrame {
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
 
         #[cfg(feature = "dynamic_group_by")]
         {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                memory_limit,
                 keys,
                 maintain_order: true,
                 dynamic_options: None,
@@ -1051,6 +1141,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                memory_limit,
                 keys,
                 maintain_order: true,
             }
@@ -1257,6 +1348,7 @@ impl LazyFrame {
     /// ```
     pub fn with_column(self, expr: Expr) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self
             .get_plan_builder()
             .with_columns(
@@ -1267,7 +1359,7 @@ impl LazyFrame {
                 },
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Add or replace multiple columns, given as expressions, to a DataFrame.
@@ -1309,8 +1401,9 @@ impl LazyFrame {
 
     fn with_columns_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().with_columns(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     pub fn with_context<C: AsRef<[LazyFrame]>>(self, contexts: C) -> LazyFrame {
@@ -1320,8 +1413,9 @@ impl LazyFrame {
             .map(|lf| lf.logical_plan.clone())
             .collect();
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().with_context(contexts).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Aggregate all the columns as their maximum values.
@@ -1410,8 +1504,9 @@ impl LazyFrame {
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().explode(columns).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Aggregate all the columns as the sum of their null value count.
@@ -1419,6 +1514,12 @@ impl LazyFrame {
         self.select(vec![col("*").null_count()])
     }
 
+    /// Add a column `{name}{suffix}` for every column with the null count of that column,
+    /// broadcast to the height of the DataFrame.
+    pub fn with_null_count_columns(self, suffix: &str) -> LazyFrame {
+        self.with_columns([col("*").null_count().name().suffix(suffix)])
+    }
+
     /// Drop non-unique rows and maintain the order of kept rows.
     ///
     /// `subset` is an optional `Vec` of column names to consider for uniqueness; if
@@ -1429,6 +1530,7 @@ impl LazyFrame {
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let options = DistinctOptions {
             subset: subset.map(Arc::new),
             maintain_order: true,
@@ -1436,7 +1538,7 @@ impl LazyFrame {
             ..Default::default()
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Drop non-unique rows without maintaining the order of kept rows.
@@ -1452,6 +1554,7 @@ impl LazyFrame {
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let options = DistinctOptions {
             subset: subset.map(Arc::new),
             maintain_order: false,
@@ -1459,7 +1562,7 @@ impl LazyFrame {
             ..Default::default()
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Drop rows containing None.
@@ -1468,8 +1571,9 @@ impl LazyFrame {
     /// columns are considered.
     pub fn drop_nulls(self, subset: Option<Vec<Expr>>) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().drop_nulls(subset).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Slice the DataFrame using an offset (starting row) and a length.
@@ -1483,8 +1587,9 @@ impl LazyFrame {
     /// case, the number of rows in the returned DataFrame will be less than `len`.
     pub fn slice(self, offset: i64, len: IdxSize) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().slice(offset, len).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Get the first row.
@@ -1514,8 +1619,9 @@ impl LazyFrame {
     /// See [`MeltArgs`] for information on how to melt a DataFrame.
     pub fn melt(self, args: MeltArgs) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().melt(Arc::new(args)).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Limit the DataFrame to the first `n` rows.
@@ -1549,6 +1655,7 @@ impl LazyFrame {
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self
             .get_plan_builder()
             .map(
@@ -1558,7 +1665,7 @@ impl LazyFrame {
                 name.unwrap_or("ANONYMOUS UDF"),
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     #[cfg(feature = "python")]
@@ -1570,17 +1677,19 @@ impl LazyFrame {
         validate_output: bool,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self
             .get_plan_builder()
             .map_python(function, optimizations, schema, validate_output)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     pub(crate) fn map_private(self, function: FunctionNode) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let memory_limit = self.memory_limit;
         let lp = self.get_plan_builder().map_private(function).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, memory_limit)
     }
 
     /// Add a new column at index 0 that counts the rows.
@@ -1665,6 +1774,7 @@ impl LazyFrame {
 pub struct LazyGroupBy {
     pub logical_plan: LogicalPlan,
     opt_state: OptState,
+    memory_limit: Option<usize>,
     keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
@@ -1678,6 +1788,7 @@ impl From<LazyGroupBy> for LazyFrame {
         Self {
             logical_plan: lgb.logical_plan,
             opt_state: lgb.opt_state,
+            memory_limit: lgb.memory_limit,
         }
     }
 }
@@ -1693,7 +1804,7 @@ impl LazyGroupBy {
     /// ```rust
     /// use polars_core::prelude::*;
     /// use polars_lazy::prelude::*;
-    /// use arrow::legacy::prelude::QuantileInterpolOptions;
+    /// use polars_core::prelude::QuantileInterpolOptions;
     ///
     /// fn example(df: DataFrame) -> LazyFrame {
     ///       df.lazy()
@@ -1722,7 +1833,7 @@ impl LazyGroupBy {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
             .group_by(self.keys, aggs, None, self.maintain_order)
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.opt_state, self.memory_limit)
     }
 
     /// Return first n rows of each group
@@ -1776,7 +1887,7 @@ impl LazyGroupBy {
             maintain_order: self.maintain_order,
             options: Arc::new(options),
         };
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.opt_state, self.memory_limit)
     }
 }
 
@@ -1881,6 +1992,7 @@ impl JoinBuilder {
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
+        let memory_limit = self.lf.memory_limit;
         let other = self.other.expect("with not set");
 
         // if any of the nodes reads from files we must activate this this plan as well.
@@ -1910,6 +2022,6 @@ impl JoinBuilder {
                 .into(),
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        LazyFrame::from_logical_plan(lp, opt_state, memory_limit)
     }
 }
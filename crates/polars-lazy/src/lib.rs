@@ -104,7 +104,7 @@
 //! use polars_core::prelude::*;
 //! use polars_core::df;
 //! use polars_lazy::prelude::*;
-//! use arrow::legacy::prelude::QuantileInterpolOptions;
+//! use polars_core::prelude::QuantileInterpolOptions;
 //!
 //! fn example() -> PolarsResult<DataFrame> {
 //!     let df = df!(
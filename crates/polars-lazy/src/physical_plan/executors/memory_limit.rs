@@ -0,0 +1,20 @@
+use super::*;
+
+/// Checks the estimated size of the output of `input` against a memory budget.
+pub struct MemoryLimitExec {
+    pub input: Box<dyn Executor>,
+    pub limit: usize,
+}
+
+impl Executor for MemoryLimitExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let df = self.input.execute(state)?;
+        let size = df.estimated_size();
+        polars_ensure!(
+            size <= self.limit,
+            OutOfMemory: "intermediate result of {} bytes exceeds the memory limit of {} bytes",
+            size, self.limit
+        );
+        Ok(df)
+    }
+}
@@ -7,6 +7,7 @@ mod group_by_dynamic;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod join;
+mod memory_limit;
 mod projection;
 mod projection_utils;
 #[cfg(feature = "python")]
@@ -38,6 +39,7 @@ pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
 pub(super) use self::join::*;
+pub(super) use self::memory_limit::*;
 pub(super) use self::projection::*;
 #[cfg(feature = "python")]
 pub(super) use self::python_scan::*;
@@ -144,6 +144,33 @@ pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    create_physical_plan_with_memory_limit(root, lp_arena, expr_arena, None)
+}
+
+/// Create the physical plan and, if a `memory_limit` is given, check the output of
+/// every node against it.
+pub fn create_physical_plan_with_memory_limit(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    memory_limit: Option<usize>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let executor = create_physical_plan_impl(root, lp_arena, expr_arena, memory_limit)?;
+    Ok(match memory_limit {
+        Some(limit) => Box::new(executors::MemoryLimitExec {
+            input: executor,
+            limit,
+        }),
+        None => executor,
+    })
+}
+
+fn create_physical_plan_impl(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use ALogicalPlan::*;
 
@@ -168,17 +195,21 @@ pub fn create_physical_plan(
         Union { inputs, options } => {
             let inputs = inputs
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| {
+                    create_physical_plan_with_memory_limit(node, lp_arena, expr_arena, memory_limit)
+                })
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Box::new(executors::UnionExec { inputs, options }))
         },
         Slice { input, offset, len } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         },
         Selection { input, predicate } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             let mut state = ExpressionConversionState::default();
             let predicate = create_physical_expr(
                 predicate,
@@ -277,7 +308,8 @@ pub fn create_physical_plan(
             ..
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             let mut state = ExpressionConversionState::new(POOL.current_num_threads() > expr.len());
             let phys_expr = create_physical_expressions(
                 expr.default_exprs(),
@@ -343,7 +375,8 @@ pub fn create_physical_plan(
                 Some(input_schema.as_ref()),
                 &mut Default::default(),
             )?;
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             Ok(Box::new(executors::SortExec {
                 input,
                 by_column,
@@ -351,11 +384,13 @@ pub fn create_physical_plan(
             }))
         },
         Cache { input, id, count } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             Ok(Box::new(executors::CacheExec { id, input, count }))
         },
         Distinct { input, options } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             Ok(Box::new(executors::UniqueExec { input, options }))
         },
         Aggregate {
@@ -387,7 +422,12 @@ pub fn create_physical_plan(
             let _slice = options.slice;
             #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.dynamic {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_with_memory_limit(
+                    input,
+                    lp_arena,
+                    expr_arena,
+                    memory_limit,
+                )?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
@@ -401,7 +441,12 @@ pub fn create_physical_plan(
 
             #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.rolling {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_with_memory_limit(
+                    input,
+                    lp_arena,
+                    expr_arena,
+                    memory_limit,
+                )?;
                 return Ok(Box::new(executors::GroupByRollingExec {
                     input,
                     keys: phys_keys,
@@ -423,7 +468,12 @@ pub fn create_physical_plan(
                         false
                     }
                 });
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_with_memory_limit(
+                    input,
+                    lp_arena,
+                    expr_arena,
+                    memory_limit,
+                )?;
                 let keys = keys
                     .iter()
                     .map(|node| node_to_expr(*node, expr_arena))
@@ -445,7 +495,12 @@ pub fn create_physical_plan(
                     aggs,
                 )))
             } else {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_with_memory_limit(
+                    input,
+                    lp_arena,
+                    expr_arena,
+                    memory_limit,
+                )?;
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -480,8 +535,18 @@ pub fn create_physical_plan(
                 false
             };
 
-            let input_left = create_physical_plan(input_left, lp_arena, expr_arena)?;
-            let input_right = create_physical_plan(input_right, lp_arena, expr_arena)?;
+            let input_left = create_physical_plan_with_memory_limit(
+                input_left,
+                lp_arena,
+                expr_arena,
+                memory_limit,
+            )?;
+            let input_right = create_physical_plan_with_memory_limit(
+                input_right,
+                lp_arena,
+                expr_arena,
+                memory_limit,
+            )?;
             let left_on = create_physical_expressions(
                 &left_on,
                 Context::Default,
@@ -513,7 +578,8 @@ pub fn create_physical_plan(
             options,
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
 
             let mut state =
                 ExpressionConversionState::new(POOL.current_num_threads() > exprs.len());
@@ -545,16 +611,20 @@ pub fn create_physical_plan(
         MapFunction {
             input, function, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             Ok(Box::new(executors::UdfExec { input, function }))
         },
         ExtContext {
             input, contexts, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input =
+                create_physical_plan_with_memory_limit(input, lp_arena, expr_arena, memory_limit)?;
             let contexts = contexts
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| {
+                    create_physical_plan_with_memory_limit(node, lp_arena, expr_arena, memory_limit)
+                })
                 .collect::<PolarsResult<_>>()?;
             Ok(Box::new(executors::ExternalContext { input, contexts }))
         },
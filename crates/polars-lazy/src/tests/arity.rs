@@ -75,3 +75,93 @@ fn test_lazy_ternary() {
         .unwrap();
     assert_eq!(Some(43), df.column("new").unwrap().sum::<i32>());
 }
+
+#[test]
+fn test_ternary_nested_literal() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1, 2, 3]
+    }?;
+
+    let value = AnyValue::List(Series::new("", [7i32, 8]));
+    let out = df
+        .lazy()
+        .select([when(col("a").eq(lit(2)))
+            .then(lit(LiteralValue::try_from_scalar(value)?))
+            .otherwise(lit(NULL))
+            .alias("b")])
+        .collect()?;
+
+    let b = out.column("b")?.list()?;
+    assert_eq!(b.len(), 3);
+    assert_eq!(b.null_count(), 2);
+    assert!(b
+        .get_as_series(1)
+        .unwrap()
+        .equals(&Series::new("", [7i32, 8])));
+    Ok(())
+}
+
+#[test]
+fn test_list_any_value_literal() -> PolarsResult<()> {
+    // Converting a list keeps producing a literal of its values.
+    let value = AnyValue::List(Series::new("", [7i32, 8]));
+    let lv = LiteralValue::try_from(value.clone())?;
+    assert_eq!(lv.get_datatype(), DataType::Int32);
+
+    let lv = LiteralValue::try_from_scalar(value)?;
+    assert_eq!(lv.get_datatype(), DataType::List(Box::new(DataType::Int32)));
+    Ok(())
+}
+
+#[test]
+fn test_when_then_many() -> PolarsResult<()> {
+    let n = 120;
+    let df = df![
+        "a" => (0..n + 10).collect::<Vec<i32>>(),
+    ]?;
+
+    // Even branches give integers and odd branches floats, so the output is a float.
+    let branches = (0..n)
+        .map(|i| {
+            let value = if i % 2 == 0 {
+                lit(i * 10)
+            } else {
+                lit(i as f64 + 0.5)
+            };
+            (col("a").eq(lit(i)), value)
+        })
+        .collect::<Vec<_>>();
+    let expr = when_then_many(branches, lit(-1)).alias("out");
+    // The plan grows linearly with the number of branches.
+    assert!(expr.into_iter().count() <= 6 * n as usize);
+
+    let out = df.lazy().select([expr]).collect()?;
+    let out = out.column("out")?;
+    assert_eq!(out.dtype(), &DataType::Float64);
+    let out = out.f64()?;
+    assert_eq!(out.get(0), Some(0.0));
+    assert_eq!(out.get(1), Some(1.5));
+    assert_eq!(out.get(118), Some(1180.0));
+    assert_eq!(out.get(119), Some(119.5));
+    assert_eq!(out.get(120), Some(-1.0));
+
+    // The first matching branch wins.
+    let expr = when_then_many(
+        vec![
+            (col("a").lt(lit(5)), lit("small")),
+            (col("a").lt(lit(50)), lit("medium")),
+        ],
+        lit("large"),
+    );
+    let out = df![
+        "a" => [1, 10, 100],
+    ]?
+    .lazy()
+    .select([expr.alias("size")])
+    .collect()?;
+    assert_eq!(
+        Vec::from(out.column("size")?.utf8()?),
+        &[Some("small"), Some("medium"), Some("large")]
+    );
+    Ok(())
+}
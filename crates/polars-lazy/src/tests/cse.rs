@@ -293,3 +293,37 @@ fn test_cse_columns_projections() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_replace_expr() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), None, Some(4)],
+    ]?;
+
+    let doubled = col("a") * lit(2);
+    let q = df.lazy().select([doubled
+        .replace_expr(
+            vec![lit(2), lit(4), lit(NULL)],
+            vec![lit(20), lit(40), lit(0)],
+            None,
+        )?
+        .alias("a")]);
+
+    assert!(col("a")
+        .replace_expr(vec![lit(2), lit(4)], vec![lit(20), lit(40), lit(0)], None)
+        .is_err());
+
+    // The doubled column is shared by all branches of the mapping.
+    let plan = q
+        .clone()
+        .with_comm_subexpr_elim(true)
+        .describe_optimized_plan()?;
+    assert!(plan.contains("__POLARS_CSER"), "{plan}");
+
+    let out = q.with_comm_subexpr_elim(true).collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(20), Some(40), Some(0), Some(8)]
+    );
+    Ok(())
+}
@@ -110,6 +110,37 @@ fn test_lazy_logical_plan_schema() {
     assert!(lp.schema().unwrap().get("sepal.width").is_some());
 }
 
+#[test]
+fn test_lazy_expect_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"]
+    ]?;
+    let lf = df.lazy().with_column(col("a").cast(DataType::Float64));
+
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Float64),
+        Field::new("b", DataType::Utf8),
+    ]);
+    let reversed = Schema::from_iter([
+        Field::new("b", DataType::Utf8),
+        Field::new("a", DataType::Float64),
+    ]);
+    assert!(lf.clone().expect_schema(&schema, true).is_ok());
+    assert!(lf.clone().expect_schema(&reversed, false).is_ok());
+    assert!(lf.clone().expect_schema(&reversed, true).is_err());
+
+    // The cast changed the dtype of "a".
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Int32),
+        Field::new("b", DataType::Utf8),
+    ]);
+    assert!(lf.clone().expect_schema(&schema, false).is_err());
+    let schema = Schema::from_iter([Field::new("a", DataType::Float64)]);
+    assert!(lf.expect_schema(&schema, false).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_lazy_logical_plan_join() {
     let left = df!("days" => &[0, 1, 2, 3, 4],
@@ -158,3 +189,30 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+fn test_lazy_type_coerce() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 300]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").type_coerce(DataType::UInt8)])
+        .collect()?;
+    // 300 doesn't fit in an u8, so it becomes null.
+    assert_eq!(Vec::from(out.column("a")?.u8()?), &[Some(1), None]);
+    // It is the same non-strict cast node as `cast`.
+    assert_eq!(
+        col("a").type_coerce(DataType::UInt8),
+        col("a").cast(DataType::UInt8)
+    );
+
+    let out = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::UInt8)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
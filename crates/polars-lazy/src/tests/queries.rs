@@ -1908,3 +1908,370 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_with_null_count_columns() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, None],
+        "b" => [Some("x"), Some("y"), None]
+    ]?;
+
+    let out = df.lazy().with_null_count_columns("_nulls").collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "a_nulls", "b_nulls"]);
+    assert_eq!(
+        Vec::from(out.column("a_nulls")?.idx()?),
+        &[Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b_nulls")?.idx()?),
+        &[Some(1), Some(1), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_limit_memory() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>()
+    ]?;
+    assert_eq!(df.estimated_size(), 8000);
+
+    let out = df.clone().lazy().limit_memory(10_000).sum().collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(499500));
+
+    // The scan fits, but the projection doubles the size of the intermediate result.
+    let err = df
+        .clone()
+        .lazy()
+        .limit_memory(10_000)
+        .with_column((col("a") * lit(2i64)).alias("b"))
+        .sum()
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::OutOfMemory(_)));
+
+    // The limit survives resetting the optimizations.
+    let err = df
+        .lazy()
+        .limit_memory(1_000)
+        .without_optimizations()
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::OutOfMemory(_)));
+
+    // A concat that casts to the supertypes keeps the limit of the first frame.
+    let other = df![
+        "a" => (0..1000i32).collect::<Vec<_>>()
+    ]?;
+    let args = UnionArgs {
+        to_supertypes: true,
+        ..Default::default()
+    };
+    let err = concat([df.lazy().limit_memory(10_000), other.lazy()], args)?
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::OutOfMemory(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "rolling_window", feature = "dtype-date"))]
+fn test_rolling_quantile_by_time() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+    use polars_time::Duration;
+
+    let mut t = Series::new("t", &[0i32, 1, 2, 5, 6, 10]).cast(&DataType::Date)?;
+    t.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![t, Series::new("v", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])])?;
+
+    let out = df
+        .lazy()
+        .select([col("v").rolling_quantile_by_time("t", Duration::parse("3d"), 0.5)?])
+        .collect()?;
+    // The gaps in `t` shrink the windows to the rows within the last three days.
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        &[
+            Some(1.0),
+            Some(1.5),
+            Some(2.0),
+            Some(4.0),
+            Some(4.5),
+            Some(6.0)
+        ]
+    );
+
+    assert!(col("v")
+        .rolling_quantile_by_time("t", Duration::parse("3i"), 0.5)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "serde",
+    feature = "range",
+    feature = "dtype-date",
+    feature = "dtype-time"
+))]
+fn test_serde_range_and_replace_roundtrip() -> PolarsResult<()> {
+    use polars_time::{ClosedWindow, Duration};
+    use serde_json::Value;
+
+    let hour = 3_600_000_000_000i64;
+    let df = DataFrame::new(vec![
+        Series::new("i", &[0i64, 2]),
+        Series::new("d0", &[0i32, 10]).cast(&DataType::Date)?,
+        Series::new("d1", &[2i32, 13]).cast(&DataType::Date)?,
+        Series::new("t0", &[0, hour]).cast(&DataType::Time)?,
+        Series::new("t1", &[2 * hour, 3 * hour]).cast(&DataType::Time)?,
+    ])?;
+    let q = df.lazy().select([
+        int_ranges(col("i"), col("i") + lit(3i64), 1).alias("ints"),
+        date_ranges(
+            col("d0"),
+            col("d1"),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            None,
+            None,
+        )
+        .alias("dates"),
+        time_ranges(
+            col("t0"),
+            col("t1"),
+            Duration::parse("30m"),
+            ClosedWindow::Left,
+        )
+        .alias("times"),
+        col("i")
+            .replace_expr(vec![lit(0i64)], vec![lit(10i64)], Some(lit(-1i64)))?
+            .alias("replaced"),
+    ]);
+
+    // Fields written by a newer version are ignored.
+    fn add_unknown_field(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::Object(fields)) = map.get_mut("TimeRanges") {
+                    fields.insert("num_samples".into(), Value::Null);
+                }
+                map.values_mut().for_each(add_unknown_field);
+            },
+            Value::Array(values) => values.iter_mut().for_each(add_unknown_field),
+            _ => {},
+        }
+    }
+    let mut json = serde_json::to_value(&q.logical_plan).unwrap();
+    add_unknown_field(&mut json);
+    assert!(json.to_string().contains("num_samples"));
+    let plan: LogicalPlan = serde_json::from_value(json).unwrap();
+
+    let expected = q.collect()?;
+    let out = LazyFrame::from(plan).collect()?;
+    assert!(out.equals_missing(&expected));
+    assert_eq!(
+        Vec::from(out.column("replaced")?.i64()?),
+        &[Some(10), Some(-1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "serde",
+    feature = "range",
+    feature = "rolling_window",
+    feature = "dtype-date"
+))]
+fn test_serde_duration_options_roundtrip() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+    use polars_time::{ClosedWindow, Duration};
+
+    let mut t = Series::new("t", &[0i32, 1, 2, 5, 6, 10]).cast(&DataType::Date)?;
+    t.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![
+        t,
+        Series::new("t_end", &[60i32, 61, 62, 65, 66, 70]).cast(&DataType::Date)?,
+        Series::new("v", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+    ])?;
+    let q = df.lazy().select([
+        col("v").rolling_mean(RollingOptions {
+            window_size: Duration::parse("3d"),
+            by: Some("t".into()),
+            closed_window: Some(ClosedWindow::Both),
+            ..Default::default()
+        }),
+        date_ranges(
+            col("t"),
+            col("t_end"),
+            Duration::parse("1mo1d"),
+            ClosedWindow::Right,
+            None,
+            None,
+        )
+        .alias("dates"),
+    ]);
+
+    // The durations are written as their components.
+    let json = serde_json::to_string(&q.logical_plan).unwrap();
+    assert!(json.contains(r#""months":1,"weeks":0,"days":1"#));
+    let plan: LogicalPlan = serde_json::from_str(&json).unwrap();
+
+    let expected = q.collect()?;
+    let out = LazyFrame::from(plan).collect()?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+fn test_replace_value_datetime() -> PolarsResult<()> {
+    let dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Amsterdam".into()));
+    let df = DataFrame::new(vec![Series::new("t", &[0i64, 1000, 2000]).cast(&dtype)?])?;
+
+    let tz = Some("Europe/Amsterdam".to_string());
+    let old = AnyValue::Datetime(1000, TimeUnit::Milliseconds, &tz);
+    let new = AnyValue::Datetime(5000, TimeUnit::Milliseconds, &tz);
+    let out = df
+        .lazy()
+        .select([col("t").replace_value(old, new, None)?.alias("t")])
+        .collect()?;
+
+    let t = out.column("t")?;
+    assert_eq!(t.dtype(), &dtype);
+    assert_eq!(
+        Vec::from(t.to_physical_repr().i64()?),
+        &[Some(0), Some(5000), Some(2000)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_replace_value_decimal() -> PolarsResult<()> {
+    let d = Int128Chunked::from_slice("d", &[100, 250, 300]).into_decimal_unchecked(None, 2);
+    let df = DataFrame::new(vec![d.into_series()])?;
+
+    // 2.50 is stored as 250 with a scale of 2.
+    let out = df
+        .lazy()
+        .select([col("d")
+            .replace_value(AnyValue::Decimal(250, 2), AnyValue::Decimal(999, 2), None)?
+            .alias("d")])
+        .collect()?;
+
+    let d = out.column("d")?;
+    assert_eq!(d.dtype(), &DataType::Decimal(None, Some(2)));
+    assert_eq!(
+        Vec::from(&d.decimal()?.0),
+        &[Some(100), Some(999), Some(300)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_str_common_prefix_length() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("polars"), Some("abc"), Some("pol"), Some("naïve"), None],
+        "b" => [Some("polars"), Some("xyz"), Some("polars"), Some("naïf"), Some("x")]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").str().common_prefix_length(col("b")).alias("pairs"),
+            col("a")
+                .str()
+                .common_prefix_length(lit("po"))
+                .alias("scalar"),
+        ])
+        .collect()?;
+
+    // The length is in bytes, "ï" takes two.
+    assert_eq!(
+        Vec::from(out.column("pairs")?.u32()?),
+        &[Some(6), Some(0), Some(3), Some(4), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("scalar")?.u32()?),
+        &[Some(2), Some(0), Some(2), Some(0), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_sort_by_expr() -> PolarsResult<()> {
+    let df = df![
+        "name" => [Some("ccc"), Some("a"), None, Some("bb")],
+        "v" => [1, 2, 3, 4]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .sort_by_expr(col("name").str().len_chars(), true, true)
+        .collect()?;
+    // The sort key is not part of the output.
+    assert_eq!(out.get_column_names(), &["name", "v"]);
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), Some(4), Some(2), Some(3)]
+    );
+
+    let out = df
+        .lazy()
+        .sort_by_expr(lit(10) - col("v"), false, false)
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(4), Some(3), Some(2), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-time"))]
+fn test_time_range_per_group() -> PolarsResult<()> {
+    use polars_time::{ClosedWindow, Duration};
+
+    let hour = 3_600_000_000_000i64;
+    let df = df![
+        "id" => [1, 1, 1, 2, 2, 3],
+        "t" => [0, 2 * hour, hour, 5 * hour, 5 * hour, 8 * hour],
+        "keep" => [true, true, true, true, true, false]
+    ]?
+    .lazy()
+    .with_column(col("t").cast(DataType::Time));
+
+    let t = || col("t").filter(col("keep"));
+    let out = df
+        .group_by_stable([col("id")])
+        .agg([time_range(
+            t().min(),
+            t().max(),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+        )
+        .alias("times")])
+        .collect()?;
+
+    let times = out.column("times")?;
+    assert_eq!(times.dtype(), &DataType::List(Box::new(DataType::Time)));
+    let times = times
+        .list()?
+        .into_iter()
+        .map(|s| {
+            let s = s.unwrap().cast(&DataType::Int64).unwrap();
+            s.i64()
+                .unwrap()
+                .into_no_null_iter()
+                .map(|v| v / hour)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // The group without kept rows has null bounds and gets an empty range.
+    assert_eq!(times, &[vec![0, 1, 2], vec![5], vec![]]);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars_core::prelude::*;
+use polars_ops::prelude::replace;
+
+const LEN: usize = 1_000_000;
+
+fn bench_few_matches(c: &mut Criterion) {
+    // Only the rows with value 0 match, which is 1% of them.
+    let ca = Int64Chunked::from_iter_values("a", (0..LEN as i64).map(|i| i % 100));
+    let s = ca.clone().into_series();
+    let old = Series::new("old", &[0i64]);
+    let new = Series::new("new", &[-1i64]);
+
+    c.bench_function("replace 1% matches", |b| {
+        b.iter(|| replace(&s, &old, &new, &s, None, false).unwrap())
+    });
+    // What `when(col == 0).then(-1).otherwise(col)` runs.
+    c.bench_function("when/then 1% matches", |b| {
+        b.iter(|| {
+            Int64Chunked::full("a", -1, LEN)
+                .zip_with(&ca.equal(0i64), &ca)
+                .unwrap()
+        })
+    });
+}
+
+fn bench_many_keys(c: &mut Criterion) {
+    let ca = Int64Chunked::from_iter_values("a", (0..LEN as i64).map(|i| i % 1000));
+    let s = ca.clone().into_series();
+    let keys = (0..50i64).collect::<Vec<_>>();
+    let old = Series::new("old", &keys);
+    let new = Series::new("new", &keys.iter().map(|k| -k).collect::<Vec<_>>());
+
+    // One equality mask per key, OR-ed together.
+    c.bench_function("mask union 50 keys", |b| {
+        b.iter(|| {
+            keys.iter()
+                .map(|k| ca.equal(*k))
+                .reduce(|acc, mask| &acc | &mask)
+                .unwrap()
+        })
+    });
+    // A single pass that looks up every value in a set of the keys.
+    c.bench_function("hash set 50 keys", |b| {
+        b.iter(|| {
+            let set = keys.iter().copied().collect::<PlHashSet<_>>();
+            BooleanChunked::from_iter_values("a", ca.into_no_null_iter().map(|v| set.contains(&v)))
+        })
+    });
+    c.bench_function("replace 50 keys", |b| {
+        b.iter(|| replace(&s, &old, &new, &s, None, false).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_few_matches, bench_many_keys);
+criterion_main!(benches);
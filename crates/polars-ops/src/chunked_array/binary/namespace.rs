@@ -8,6 +8,7 @@ use base64::Engine as _;
 use memchr::memmem::find;
 use polars_core::prelude::arity::binary_elementwise_values;
 
+use super::prefix::common_prefix_len;
 use super::*;
 
 pub trait BinaryNameSpaceImpl: AsBinary {
@@ -58,6 +59,26 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// The number of leading bytes that every value has in common with the value of `other`
+    /// at the same index, or with the single value of `other`.
+    fn common_prefix_len_chunked(&self, other: &BinaryChunked) -> UInt32Chunked {
+        let ca = self.as_binary();
+        match other.len() {
+            1 => match other.get(0) {
+                Some(other) => {
+                    let mut out: UInt32Chunked = ca
+                        .into_iter()
+                        .map(|opt_s| opt_s.map(|s| common_prefix_len(s, other) as u32))
+                        .collect();
+                    out.rename(ca.name());
+                    out
+                },
+                None => UInt32Chunked::full_null(ca.name(), ca.len()),
+            },
+            _ => binary_elementwise_values(ca, other, |a, b| common_prefix_len(a, b) as u32),
+        }
+    }
+
     fn ends_with_chunked(&self, suffix: &BinaryChunked) -> BooleanChunked {
         let ca = self.as_binary();
         match suffix.len() {
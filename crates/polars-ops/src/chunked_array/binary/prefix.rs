@@ -0,0 +1,44 @@
+/// The number of leading bytes that `a` and `b` have in common.
+///
+/// The bytes are compared eight at a time as words: the first differing byte is the lowest
+/// set byte of the xor of two little-endian words.
+pub(super) fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    let len = a.len().min(b.len());
+    let (a, b) = (&a[..len], &b[..len]);
+
+    let mut offset = 0;
+    for (wa, wb) in a.chunks_exact(8).zip(b.chunks_exact(8)) {
+        let wa = u64::from_le_bytes(wa.try_into().unwrap());
+        let wb = u64::from_le_bytes(wb.try_into().unwrap());
+        let diff = wa ^ wb;
+        if diff != 0 {
+            return offset + (diff.trailing_zeros() / 8) as usize;
+        }
+        offset += 8;
+    }
+    offset
+        + a[offset..]
+            .iter()
+            .zip(&b[offset..])
+            .take_while(|(x, y)| x == y)
+            .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_common_prefix_len() {
+        let long = b"the quick brown fox jumps over the lazy dog";
+        for i in 0..long.len() {
+            let mut other = long.to_vec();
+            other[i] ^= 1;
+            assert_eq!(common_prefix_len(long, &other), i);
+            assert_eq!(common_prefix_len(long, &long[..i]), i);
+        }
+        assert_eq!(common_prefix_len(long, long), long.len());
+        assert_eq!(common_prefix_len(b"", b"abc"), 0);
+        assert_eq!(common_prefix_len(b"abc", b"xyz"), 0);
+    }
+}
@@ -35,6 +35,7 @@ pub use list::*;
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
 pub use repeat_by::*;
+pub(crate) use set::set_at_indices;
 pub use set::ChunkedSet;
 pub use strings::*;
 #[cfg(feature = "top_k")]
@@ -3,6 +3,7 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::MutableBitmap;
 use polars_core::utils::arrow::types::NativeType;
+use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::index::check_bounds;
 
 pub trait ChunkedSet<T: Copy> {
@@ -187,3 +188,83 @@ impl ChunkedSet<bool> for &BooleanChunked {
         Ok(ca.into_series())
     }
 }
+
+/// Return a copy of `s` with the values at `indices` replaced by `values`.
+///
+/// The buffers of `s` are only copied if they are shared.
+pub(crate) fn set_at_indices(s: &Series, indices: &IdxCa, values: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == indices.len() || values.len() == 1,
+        ShapeMismatch: "`values` ({}) must have the same length as `indices` ({}) or have length 1",
+        values.len(), indices.len()
+    );
+    polars_ensure!(
+        indices.null_count() == 0,
+        ComputeError: "index values should not be null"
+    );
+    #[cfg(feature = "dtype-categorical")]
+    if let DataType::Categorical(_) = s.dtype() {
+        polars_bail!(opq = set_at_indices, s.dtype());
+    }
+
+    let values = values.broadcast_to(indices.len())?.cast(s.dtype())?;
+    let values = values.to_physical_repr();
+    let indices = indices.rechunk();
+    let idx = indices.downcast_iter().next().unwrap().values().as_slice();
+
+    let phys = s.to_physical_repr();
+    let out = match phys.dtype() {
+        DataType::Boolean => phys.bool().unwrap().set_at_idx2(idx, values.bool()?),
+        DataType::Utf8 => phys.utf8().unwrap().set_at_idx2(idx, values.utf8()?),
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = phys.as_ref().as_ref().as_ref();
+                let values: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+                ca.clone().set_at_idx2(idx, values)
+            })
+        },
+        dt => polars_bail!(opq = set_at_indices, dt),
+    }?;
+    out.cast(s.dtype())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_at_indices() -> PolarsResult<()> {
+        let s = Series::new("a", &[1i32, 2, 3, 4]);
+        let idx = IdxCa::new("idx", &[3, 0]);
+
+        let values = Series::new("v", &[Some(40i32), None]);
+        let out = set_at_indices(&s, &idx, &values)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[None, Some(2), Some(3), Some(40)]);
+        // The input is left untouched.
+        assert_eq!(Vec::from(s.i32()?), &[Some(1), Some(2), Some(3), Some(4)]);
+
+        let values = Series::new("v", &[0i64]);
+        let out = set_at_indices(&s, &idx, &values)?;
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(2), Some(3), Some(0)]);
+
+        let s = Series::new("a", &["x", "y", "z"]);
+        let idx = IdxCa::new("idx", &[1]);
+        let out = set_at_indices(&s, &idx, &Series::new("v", &["b"]))?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("x"), Some("b"), Some("z")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_at_indices_invalid() {
+        let s = Series::new("a", &[1i32, 2, 3]);
+        let idx = IdxCa::new("idx", &[3]);
+        let values = Series::new("v", &[0i32]);
+        assert!(set_at_indices(&s, &idx, &values).is_err());
+
+        let idx = IdxCa::new("idx", &[0, 1]);
+        let values = Series::new("v", &[0i32, 1, 2]);
+        assert!(set_at_indices(&s, &idx, &values).is_err());
+    }
+}
@@ -131,8 +131,14 @@ pub(super) fn to_uppercase<'a>(ca: &'a Utf8Chunked) -> Utf8Chunked {
     ca.apply_mut(f)
 }
 
+/// Capitalize the first character of every word and lowercase the rest.
+///
+/// Words are delimited by the Unicode word boundaries, so punctuation such as
+/// hyphens and brackets starts a new word as well as whitespace does.
 #[cfg(feature = "nightly")]
 pub(super) fn to_titlecase<'a>(ca: &'a Utf8Chunked) -> Utf8Chunked {
+    use unicode_segmentation::UnicodeSegmentation;
+
     // Amortize allocation.
     let mut buf = Vec::new();
 
@@ -147,14 +153,12 @@ pub(super) fn to_titlecase<'a>(ca: &'a Utf8Chunked) -> Utf8Chunked {
         buf.clear();
         let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
 
-        let mut next_is_upper = true;
-        for c in lowercased.chars() {
-            if next_is_upper {
-                s.extend(c.to_uppercase());
-            } else {
-                s.push(c);
+        for word in lowercased.split_word_bounds() {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                s.extend(first.to_uppercase());
+                s.push_str(chars.as_str());
             }
-            next_is_upper = c.is_whitespace();
         }
 
         // Put buf back for next iteration.
@@ -166,3 +170,38 @@ pub(super) fn to_titlecase<'a>(ca: &'a Utf8Chunked) -> Utf8Chunked {
     };
     ca.apply_mut(f)
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn test_to_titlecase() {
+        let ca = Utf8Chunked::new(
+            "a",
+            &[
+                Some("über alles"),
+                Some("hello-WORLD (foo.bar)"),
+                Some("1st place, 2ND"),
+                Some("u\u{308}ber"),
+                Some("don't stop"),
+                None,
+            ],
+        );
+        let out = to_titlecase(&ca);
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[
+                Some("Über Alles"),
+                Some("Hello-World (Foo.bar)"),
+                Some("1st Place, 2nd"),
+                Some("U\u{308}ber"),
+                Some("Don't Stop"),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use polars_core::prelude::Utf8Chunked;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+
+// Only nonspacing marks are accents. Spacing marks, e.g. the vowel signs of Indic scripts, are
+// part of the letters.
+fn is_nonspacing_mark(c: char) -> bool {
+    get_general_category(c) == GeneralCategory::NonspacingMark
+}
+
+fn remove_diacritics_helper(s: Option<&str>) -> Option<String> {
+    s.map(|v| {
+        if v.is_ascii() {
+            v.to_string()
+        } else {
+            // Decompose, drop the nonspacing marks and compose what is left.
+            v.nfd().filter(|c| !is_nonspacing_mark(*c)).nfc().collect()
+        }
+    })
+}
+
+pub fn remove_diacritics(ca: &Utf8Chunked) -> Utf8Chunked {
+    ca.apply_generic(remove_diacritics_helper)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_remove_diacritics() {
+        let ca = Utf8Chunked::new(
+            "a",
+            &[
+                Some("café"),
+                Some("naïve"),
+                Some("Ångström"),
+                Some("façade"),
+                Some("plain"),
+                None,
+            ],
+        );
+        let out = remove_diacritics(&ca);
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[
+                Some("cafe"),
+                Some("naive"),
+                Some("Angstrom"),
+                Some("facade"),
+                Some("plain"),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_remove_diacritics_keeps_spacing_marks() {
+        // The Devanagari vowel signs are spacing marks (Mc).
+        let ca = Utf8Chunked::new("a", &["किताब", "कोशिश"]);
+        let out = remove_diacritics(&ca);
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[Some("किताब"), Some("कोशिश")]
+        );
+    }
+}
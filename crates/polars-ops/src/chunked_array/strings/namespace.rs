@@ -9,7 +9,6 @@ use polars_core::export::num::Num;
 use polars_core::export::regex::Regex;
 use polars_core::prelude::arity::*;
 use polars_utils::cache::FastFixedCache;
-use regex::escape;
 
 use super::*;
 #[cfg(feature = "binary_encoding")]
@@ -450,11 +449,10 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
     /// Count all successive non-overlapping regex matches.
     fn count_matches(&self, pat: &str, literal: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_utf8();
-        let reg = if literal {
-            Regex::new(escape(pat).as_str())?
-        } else {
-            Regex::new(pat)?
-        };
+        if literal {
+            return Ok(ca.apply_generic(|opt_s| opt_s.map(|s| s.matches(pat).count() as u32)));
+        }
+        let reg = Regex::new(pat)?;
 
         Ok(ca.apply_generic(|opt_s| opt_s.map(|s| reg.find_iter(s).count() as u32)))
     }
@@ -472,14 +470,9 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         let mut reg_cache = FastFixedCache::new((ca.len() as f64).sqrt() as usize);
         let op = move |opt_s: Option<&str>, opt_pat: Option<&str>| -> PolarsResult<Option<u32>> {
             match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) if literal => Ok(Some(s.matches(pat).count() as u32)),
                 (Some(s), Some(pat)) => {
-                    let reg = reg_cache.get_or_insert_with(pat, |p| {
-                        if literal {
-                            Regex::new(escape(p).as_str()).unwrap()
-                        } else {
-                            Regex::new(p).unwrap()
-                        }
-                    });
+                    let reg = reg_cache.get_or_insert_with(pat, |p| Regex::new(p).unwrap());
                     Ok(Some(reg.find_iter(s).count() as u32))
                 },
                 _ => Ok(None),
@@ -520,6 +513,14 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         ca + other
     }
 
+    /// Remove the diacritical marks (accents, cedillas, umlauts, ...) from the string values.
+    #[must_use]
+    #[cfg(feature = "string_remove_diacritics")]
+    fn remove_diacritics(&self) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        diacritics::remove_diacritics(ca)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
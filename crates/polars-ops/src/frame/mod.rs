@@ -111,4 +111,18 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Cross-tabulate the columns `row` and `col`, aggregating `values` in every cell.
+    ///
+    /// See [`pivot::cross_tab`].
+    #[cfg(feature = "pivot")]
+    fn cross_tab(
+        &self,
+        row: &str,
+        col: &str,
+        values: &str,
+        agg: pivot::AggFunc,
+    ) -> PolarsResult<DataFrame> {
+        pivot::cross_tab(self.to_df(), row, col, values, agg)
+    }
 }
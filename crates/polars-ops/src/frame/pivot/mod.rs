@@ -23,6 +23,28 @@ pub enum PivotAgg {
     Expr(Arc<dyn PhysicalAggExpr + Send + Sync>),
 }
 
+/// The aggregation of the cells of a [`cross_tab`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggFunc {
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+impl From<AggFunc> for PivotAgg {
+    fn from(agg: AggFunc) -> Self {
+        match agg {
+            AggFunc::Count => PivotAgg::Count,
+            AggFunc::Sum => PivotAgg::Sum,
+            AggFunc::Mean => PivotAgg::Mean,
+            AggFunc::Min => PivotAgg::Min,
+            AggFunc::Max => PivotAgg::Max,
+        }
+    }
+}
+
 fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
     // restore logical type
     match (logical_type, s.dtype()) {
@@ -164,6 +186,35 @@ where
     )
 }
 
+/// Cross-tabulate two columns of `df`.
+///
+/// The output has the unique values of `row` as its first column, sorted, and a column for
+/// every unique value of `col`, sorted by name. Every cell holds the aggregation `agg` of the
+/// `values` in the rows with that pair of values. Pairs that do not occur have a count of zero
+/// and are null for the other aggregations.
+pub fn cross_tab(
+    df: &DataFrame,
+    row: &str,
+    col: &str,
+    values: &str,
+    agg: AggFunc,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        row != col,
+        InvalidOperation: "cannot cross-tabulate column '{}' with itself", row
+    );
+    let out = pivot_stable(df, [values], [row], [col], true, Some(agg.into()), None)?;
+    let out = out.sort([row], false, true)?;
+    if agg != AggFunc::Count {
+        return Ok(out);
+    }
+    let mut columns = out.get_columns().to_vec();
+    for s in &mut columns[1..] {
+        *s = s.fill_null(FillNullStrategy::Zero)?;
+    }
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn pivot_impl(
     pivot_df: &DataFrame,
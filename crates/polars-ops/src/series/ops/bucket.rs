@@ -0,0 +1,66 @@
+use arrow::legacy::kernels::bucket::power_of_two_bucket_idx;
+use polars_core::prelude::*;
+use polars_core::utils::CustomIterTools;
+
+/// The index of the power of two bucket of every value in `s`, bucket `k` holds the values in
+/// `[2^k, 2^(k+1))`.
+///
+/// Values beyond the last of the `num_buckets` buckets go into the last bucket. Values that are
+/// zero or negative have no bucket and give a null, as do nulls.
+pub fn power_of_two_bucket(s: &Series, num_buckets: u32) -> PolarsResult<Series> {
+    polars_ensure!(
+        num_buckets > 0,
+        ComputeError: "`num_buckets` for `power_of_two_bucket` must be positive"
+    );
+    let dtype = s.dtype();
+    polars_ensure!(dtype.is_integer(), opq = power_of_two_bucket, dtype);
+
+    let mut out: UInt32Chunked = if dtype.is_signed_integer() {
+        let s = s.cast(&DataType::Int64)?;
+        s.i64()
+            .unwrap()
+            .into_iter()
+            .map(|opt_v| {
+                let v = opt_v.filter(|v| *v > 0)?;
+                power_of_two_bucket_idx(v as u64, num_buckets)
+            })
+            .collect_trusted()
+    } else {
+        let s = s.cast(&DataType::UInt64)?;
+        s.u64()
+            .unwrap()
+            .into_iter()
+            .map(|opt_v| power_of_two_bucket_idx(opt_v?, num_buckets))
+            .collect_trusted()
+    };
+    out.rename(s.name());
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_of_two_bucket() -> PolarsResult<()> {
+        let s = Series::new(
+            "latency",
+            &[Some(-3i32), Some(0), Some(1), Some(5), None, Some(64)],
+        );
+        let out = power_of_two_bucket(&s, 4)?;
+        assert_eq!(out.name(), "latency");
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[None, None, Some(0), Some(2), None, Some(3)]
+        );
+
+        let s = Series::new("a", &[u64::MAX, 2]);
+        let out = power_of_two_bucket(&s, 64)?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(63), Some(1)]);
+
+        assert!(power_of_two_bucket(&s, 0).is_err());
+        let s = Series::new("a", &[1.0f64]);
+        assert!(power_of_two_bucket(&s, 4).is_err());
+        Ok(())
+    }
+}
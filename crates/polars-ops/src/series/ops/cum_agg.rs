@@ -4,7 +4,9 @@ use std::ops::{Add, AddAssign, Mul};
 use num_traits::Bounded;
 use polars_core::prelude::*;
 use polars_core::utils::{CustomIterTools, NoNull};
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type,
+};
 
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
@@ -228,3 +230,122 @@ pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
         Ok(ca.into_series())
     }
 }
+
+/// The number of distinct values up to and including every row. A null counts as one more
+/// distinct value.
+pub fn cumulative_unique_count(s: &Series) -> PolarsResult<Series> {
+    use arrow::legacy::kernels::unique::{
+        cumulative_unique_count as cumulative_unique_count_slice, cumulative_unique_count_iter,
+    };
+
+    let s = s.to_physical_repr();
+    let counts = match s.dtype() {
+        DataType::Boolean => cumulative_unique_count_iter(s.bool()?),
+        DataType::Utf8 => cumulative_unique_count_iter(s.utf8()?),
+        DataType::Binary => cumulative_unique_count_iter(s.binary()?),
+        dt if dt.is_numeric() => {
+            let s = match dt {
+                DataType::Float32 => s.bit_repr_small().into_series(),
+                DataType::Float64 => s.bit_repr_large().into_series(),
+                _ => s.clone(),
+            };
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                let ca = ca.rechunk();
+                let arr = ca.downcast_iter().next().unwrap();
+                if arr.null_count() == 0 {
+                    cumulative_unique_count_slice(arr.values().as_slice())
+                } else {
+                    cumulative_unique_count_iter(arr)
+                }
+            })
+        },
+        dt => polars_bail!(opq = cumulative_unique_count, dt),
+    };
+    Ok(UInt32Chunked::from_vec(s.name(), counts).into_series())
+}
+
+/// The cumulative integral of `y` by the trapezoidal rule. The sample points are given by `x`,
+/// or have unit spacing if `x` is `None`.
+///
+/// Nulls in `x` or `y` produce a null, and the next trapezoid starts at the last non-null point.
+pub fn cumulative_trapz(y: &Series, x: Option<&Series>) -> PolarsResult<Series> {
+    use arrow::legacy::kernels::trapz::{cumulative_trapz, cumulative_trapz_iter};
+
+    polars_ensure!(y.dtype().is_numeric(), opq = cumulative_trapz, y.dtype());
+    let y_f64 = y.cast(&DataType::Float64)?;
+    let y_ca = y_f64.f64()?.rechunk();
+    let out = match x {
+        Some(x) => {
+            polars_ensure!(
+                x.len() == y.len(),
+                ShapeMismatch: "`x` has length {} but `y` has length {}", x.len(), y.len()
+            );
+            polars_ensure!(x.dtype().is_numeric(), opq = cumulative_trapz, x.dtype());
+            let x = x.cast(&DataType::Float64)?;
+            let x_ca = x.f64()?.rechunk();
+            match (x_ca.cont_slice(), y_ca.cont_slice()) {
+                (Ok(xs), Ok(ys)) => {
+                    Float64Chunked::from_vec(y.name(), cumulative_trapz(ys, Some(xs)))
+                },
+                _ => Float64Chunked::from_iter_options(
+                    y.name(),
+                    cumulative_trapz_iter(x_ca.into_iter().zip(&y_ca)).into_iter(),
+                ),
+            }
+        },
+        None => match y_ca.cont_slice() {
+            Ok(ys) => Float64Chunked::from_vec(y.name(), cumulative_trapz(ys, None)),
+            _ => Float64Chunked::from_iter_options(
+                y.name(),
+                cumulative_trapz_iter((0..).map(|i| Some(i as f64)).zip(&y_ca)).into_iter(),
+            ),
+        },
+    };
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::series::SeriesMethods;
+
+    #[test]
+    fn test_cumulative_unique_count() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1.0), None, Some(1.0), Some(f64::NAN), None]);
+        let out = cumulative_unique_count(&s)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[Some(1), Some(2), Some(2), Some(3), Some(3)]
+        );
+
+        let s = Series::new("a", &["x", "y", "x", "z"]);
+        let out = cumulative_unique_count(&s)?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(1), Some(2), Some(2), Some(3)]);
+
+        let s = Series::new("a", &[3i64, 3, 1]);
+        let out = s.cumulative_unique_count()?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(1), Some(1), Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cumulative_trapz() -> PolarsResult<()> {
+        let y = Series::new("y", &[1i32, 2, 3]);
+        let out = cumulative_trapz(&y, None)?;
+        assert_eq!(out.name(), "y");
+        assert_eq!(Vec::from(out.f64()?), &[Some(0.0), Some(1.5), Some(4.0)]);
+
+        let x = Series::new("x", &[0.0, 1.0, 3.0, 4.0]);
+        let y = Series::new("y", &[Some(2.0), None, Some(4.0), Some(4.0)]);
+        let out = cumulative_trapz(&y, Some(&x))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), None, Some(9.0), Some(13.0)]
+        );
+
+        assert!(cumulative_trapz(&y, Some(&x.slice(0, 2))).is_err());
+        Ok(())
+    }
+}
@@ -20,3 +20,76 @@ pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Ser
         },
     }
 }
+
+/// The numerical gradient of `y` over the sample points `x`, or over unit spacing if `x` is
+/// `None`. Interior points use central differences and the boundaries one-sided differences,
+/// like NumPy's `gradient`.
+///
+/// A value is null if any of the values of `y` it is computed from is null.
+pub fn gradient(y: &Series, x: Option<&Series>) -> PolarsResult<Series> {
+    use arrow::legacy::kernels::gradient::gradient;
+
+    polars_ensure!(y.dtype().is_numeric(), opq = gradient, y.dtype());
+    polars_ensure!(
+        y.len() != 1,
+        ComputeError: "the gradient needs at least two values"
+    );
+    let y_f64 = y.cast(&DataType::Float64)?;
+    let y_ca = y_f64.f64()?.rechunk();
+    let arr = y_ca.downcast_iter().next().unwrap();
+
+    let values = match x {
+        Some(x) => {
+            polars_ensure!(x.dtype().is_numeric(), opq = gradient, x.dtype());
+            polars_ensure!(
+                x.len() == y.len(),
+                ShapeMismatch: "`x` has length {} but `y` has length {}", x.len(), y.len()
+            );
+            polars_ensure!(
+                x.null_count() == 0,
+                ComputeError: "the sample points `x` of a gradient may not contain nulls"
+            );
+            let x = x.cast(&DataType::Float64)?;
+            let x_ca = x.f64()?.rechunk();
+            gradient(arr.values(), Some(x_ca.cont_slice().unwrap()))
+        },
+        None => gradient(arr.values(), None),
+    };
+
+    // A value is only valid if its whole stencil is valid.
+    let validity = arr.validity().map(|valid| {
+        let n = valid.len();
+        (0..n)
+            .map(|i| (i.saturating_sub(1)..=(i + 1).min(n - 1)).all(|j| valid.get_bit(j)))
+            .collect()
+    });
+    Ok(Float64Chunked::from_vec_validity(y.name(), values, validity).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gradient() -> PolarsResult<()> {
+        let y = Series::new("y", &[1i32, 2, 4, 7, 11]);
+        let out = gradient(&y, None)?;
+        assert_eq!(out.name(), "y");
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), Some(1.5), Some(2.5), Some(3.5), Some(4.0)]
+        );
+
+        let x = Series::new("x", &[0.0, 1.0, 1.5, 3.5, 4.0]);
+        let y = Series::new("y", &[Some(1.0), Some(2.0), Some(4.0), Some(7.0), None]);
+        let out = gradient(&y, Some(&x))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), Some(3.0), Some(3.5), None, None]
+        );
+
+        assert!(gradient(&y, Some(&x.slice(0, 2))).is_err());
+        assert!(gradient(&y.slice(0, 1), None).is_err());
+        Ok(())
+    }
+}
@@ -69,14 +69,14 @@ pub fn all_horizontal(s: &[Series]) -> PolarsResult<Series> {
 
 #[cfg(feature = "zip_with")]
 pub fn max_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
-    let df = DataFrame::new_no_checks(Vec::from(s));
+    let df = DataFrame::new_with_broadcast(Vec::from(s))?;
     df.max_horizontal()
         .map(|opt_s| opt_s.map(|s| s.with_name("max")))
 }
 
 #[cfg(feature = "zip_with")]
 pub fn min_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
-    let df = DataFrame::new_no_checks(Vec::from(s));
+    let df = DataFrame::new_with_broadcast(Vec::from(s))?;
     df.min_horizontal()
         .map(|opt_s| opt_s.map(|s| s.with_name("min")))
 }
@@ -0,0 +1,138 @@
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+/// Above this many strings [`pairwise_levenshtein`] warns about its quadratic cost.
+const PAIRWISE_LEVENSHTEIN_WARN_LEN: usize = 1000;
+
+/// The Levenshtein distance between `a` and `b`, counted in chars.
+///
+/// With a `max_dist`, the computation stops as soon as the distance is known to exceed it and
+/// returns `max_dist + 1`.
+fn levenshtein(a: &[char], b: &[char], max_dist: Option<usize>) -> usize {
+    let limit = max_dist.map_or(usize::MAX, |d| d + 1);
+    // Every char of the length difference needs an insertion.
+    if a.len().abs_diff(b.len()) >= limit {
+        return limit;
+    }
+
+    // `row[j]` holds the distance between the current prefix of `a` and `b[..j]`.
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        let mut row_min = row[0];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = diag + (ca != cb) as usize;
+            diag = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diag + 1);
+            row_min = row_min.min(row[j + 1]);
+        }
+        // The distances never decrease from one row to the next.
+        if row_min >= limit {
+            return limit;
+        }
+    }
+    row[b.len()].min(limit)
+}
+
+/// The Levenshtein distance between every pair of strings in `s`, as a square matrix.
+///
+/// Column `column_j` holds the distances from the `j`-th string to all strings, so the output
+/// is symmetric with zeros on the diagonal. Distances to a null are null. With a `max_dist`,
+/// any distance above it is reported as `max_dist + 1`, which saves most of the work for
+/// strings that are far apart.
+///
+/// The number of distances is quadratic in the length of `s`; a warning is raised for more
+/// than 1000 strings.
+pub fn pairwise_levenshtein(s: &Series, max_dist: Option<usize>) -> PolarsResult<DataFrame> {
+    let ca = s.utf8()?;
+    let n = ca.len();
+    if n > PAIRWISE_LEVENSHTEIN_WARN_LEN {
+        polars_warn!(
+            "`pairwise_levenshtein` computes {} distances for {} strings, this may be slow",
+            n * (n - 1) / 2,
+            n
+        );
+    }
+    let chars = ca
+        .into_iter()
+        .map(|opt_s| opt_s.map(|s| s.chars().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    // Only compute the upper triangle, `upper[i][j - i - 1]` is the distance of `i` and `j`.
+    let upper = POOL.install(|| {
+        (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let a = chars[i].as_deref();
+                chars[i + 1..]
+                    .iter()
+                    .map(|b| Some(levenshtein(a?, b.as_deref()?, max_dist) as u32))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let columns = (0..n)
+        .map(|j| {
+            let mut col: UInt32Chunked = (0..n)
+                .map(|i| match i.cmp(&j) {
+                    std::cmp::Ordering::Less => upper[i][j - i - 1],
+                    std::cmp::Ordering::Equal => chars[i].as_ref().map(|_| 0),
+                    std::cmp::Ordering::Greater => upper[j][i - j - 1],
+                })
+                .collect();
+            col.rename(&format!("column_{j}"));
+            col.into_series()
+        })
+        .collect::<Vec<_>>();
+    DataFrame::new(columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dist(a: &str, b: &str, max_dist: Option<usize>) -> usize {
+        let a = a.chars().collect::<Vec<_>>();
+        let b = b.chars().collect::<Vec<_>>();
+        levenshtein(&a, &b, max_dist)
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(dist("kitten", "sitting", None), 3);
+        assert_eq!(dist("", "abc", None), 3);
+        assert_eq!(dist("flaw", "lawn", None), 2);
+        assert_eq!(dist("naïve", "naive", None), 1);
+        assert_eq!(dist("kitten", "sitting", Some(3)), 3);
+        assert_eq!(dist("kitten", "sitting", Some(1)), 2);
+        assert_eq!(dist("a", "abcdef", Some(2)), 3);
+    }
+
+    #[test]
+    fn test_pairwise_levenshtein() -> PolarsResult<()> {
+        let s = Series::new("s", &[Some("kitten"), Some("sitting"), None, Some("kit")]);
+        let out = pairwise_levenshtein(&s, None)?;
+        assert_eq!(out.shape(), (4, 4));
+        assert_eq!(
+            Vec::from(out.column("column_0")?.u32()?),
+            &[Some(0), Some(3), None, Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("column_1")?.u32()?),
+            &[Some(3), Some(0), None, Some(5)]
+        );
+        assert_eq!(out.column("column_2")?.null_count(), 4);
+
+        let out = pairwise_levenshtein(&s, Some(3))?;
+        assert_eq!(
+            Vec::from(out.column("column_3")?.u32()?),
+            &[Some(3), Some(4), None, Some(0)]
+        );
+
+        assert!(pairwise_levenshtein(&Series::new("a", &[1, 2]), None).is_err());
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ mod approx_algo;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
+mod bucket;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
@@ -29,6 +30,7 @@ mod is_in;
 mod is_last_distinct;
 #[cfg(feature = "is_unique")]
 mod is_unique;
+mod levenshtein;
 #[cfg(feature = "log")]
 mod log;
 #[cfg(feature = "moment")]
@@ -37,6 +39,8 @@ mod moment;
 mod pct_change;
 #[cfg(feature = "rank")]
 mod rank;
+#[cfg(feature = "replace")]
+mod replace;
 #[cfg(feature = "rle")]
 mod rle;
 #[cfg(feature = "rolling_window")]
@@ -58,6 +62,7 @@ pub use approx_algo::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
+pub use bucket::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
@@ -82,6 +87,7 @@ pub use is_in::*;
 pub use is_last_distinct::*;
 #[cfg(feature = "is_unique")]
 pub use is_unique::*;
+pub use levenshtein::*;
 #[cfg(feature = "log")]
 pub use log::*;
 #[cfg(feature = "moment")]
@@ -91,6 +97,8 @@ pub use pct_change::*;
 use polars_core::prelude::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
+#[cfg(feature = "replace")]
+pub use replace::*;
 #[cfg(feature = "rle")]
 pub use rle::*;
 #[cfg(feature = "rolling_window")]
@@ -0,0 +1,638 @@
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::slice::partition_point_total;
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrd, TotalOrdWrap};
+
+/// Replace values by different values of the same data type.
+///
+/// Every value in `s` that is found in `old` is replaced by the value of `new` at the same
+/// position. All other values are taken from `default`, which is either a unit-length series
+/// or has the same length as `s`. Pass `s` itself as `default` to keep unmatched values.
+///
+/// `new` must have the same length as `old` or length 1, in which case that single value is
+/// used for every match.
+///
+/// If `ascii_case_insensitive` is set, string values match keys in `old` that only differ in
+/// ASCII case. Unmatched values are still taken from `default` as is.
+pub fn replace(
+    s: &Series,
+    old: &Series,
+    new: &Series,
+    default: &Series,
+    return_dtype: Option<DataType>,
+    ascii_case_insensitive: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        default.len() == s.len() || default.len() == 1,
+        InvalidOperation: "`default` input for `replace` must have the same length as the input or have length 1"
+    );
+    let return_dtype = match return_dtype {
+        Some(dtype) => dtype,
+        None => try_get_supertype(new.dtype(), default.dtype())?,
+    };
+    replace_impl(
+        s,
+        old,
+        new,
+        Some(default),
+        return_dtype,
+        ascii_case_insensitive,
+    )
+}
+
+/// Replace values by different values, raising an error for values that are not replaced.
+///
+/// This works like [`replace`], except that without a `default` every non-null value in `s`
+/// must be found in `old`. Nulls that are not replaced stay null. The output dtype is
+/// `return_dtype` if given and the supertype of `new` and `default` otherwise.
+pub fn replace_strict(
+    s: &Series,
+    old: &Series,
+    new: &Series,
+    default: Option<&Series>,
+    return_dtype: Option<DataType>,
+) -> PolarsResult<Series> {
+    match default {
+        Some(default) => replace(s, old, new, default, return_dtype, false),
+        None => {
+            let return_dtype = return_dtype.unwrap_or_else(|| new.dtype().clone());
+            replace_impl(s, old, new, None, return_dtype, false)
+        },
+    }
+}
+
+fn replace_impl(
+    s: &Series,
+    old: &Series,
+    new: &Series,
+    default: Option<&Series>,
+    return_dtype: DataType,
+    ascii_case_insensitive: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        new.len() == old.len() || new.len() == 1,
+        InvalidOperation: "`new` input for `replace` must have the same length as `old` or have length 1"
+    );
+    // Without a default every non-null value has to be replaced.
+    let strict = default.is_none();
+    let default = match default {
+        Some(default) => default.cast_with_context(&return_dtype, "default", "replace", false)?,
+        None => Series::full_null("", 1, &return_dtype),
+    };
+
+    if old.is_empty() {
+        polars_ensure!(
+            !strict || s.null_count() == s.len(),
+            InvalidOperation: "not all values of the input were replaced in strict `replace`"
+        );
+        let mut out = default.broadcast_to(s.len())?;
+        out.rename(s.name());
+        return Ok(out);
+    }
+
+    let (s_cmp, old) = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => (
+            s.cast(&DataType::Utf8)?,
+            old.cast_with_context(&DataType::Utf8, "old", "replace", true)?,
+        ),
+        dtype => (
+            s.clone(),
+            old.cast_with_context(dtype, "old", "replace", true)?,
+        ),
+    };
+    let (s_cmp, old) = if ascii_case_insensitive {
+        polars_ensure!(
+            s_cmp.dtype() == &DataType::Utf8,
+            InvalidOperation: "case-insensitive `replace` is only supported on strings, got {}", s.dtype()
+        );
+        (ascii_lowercase(&s_cmp)?, ascii_lowercase(&old)?)
+    } else {
+        (s_cmp, old)
+    };
+    let new = new.cast_with_context(&return_dtype, "new", "replace", false)?;
+
+    let matches = get_replacement_indices(&s_cmp, &old)?;
+    if strict {
+        let all_replaced = matches
+            .iter()
+            .zip(s.is_null().into_no_null_iter())
+            .all(|(m, is_null)| m.is_some() || is_null);
+        polars_ensure!(
+            all_replaced,
+            InvalidOperation: "not all values of the input were replaced in strict `replace`"
+        );
+    }
+    let mut out = gather_replaced(&matches, &new, default)?;
+    out.rename(s.name());
+    Ok(out)
+}
+
+/// Run [`replace`] on a single field of a struct column.
+///
+/// The field named `field_name` is replaced and all other fields are kept as is. Without a
+/// `default`, unmatched values of the field are kept.
+#[cfg(feature = "dtype-struct")]
+pub fn replace_field(
+    s: &Series,
+    field_name: &str,
+    old: &Series,
+    new: &Series,
+    default: Option<&Series>,
+) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let field = ca.field_by_name(field_name)?;
+    let replaced = replace(&field, old, new, default.unwrap_or(&field), None, false)?;
+    let fields = ca
+        .fields()
+        .iter()
+        .map(|f| {
+            if f.name() == field_name {
+                replaced.clone()
+            } else {
+                f.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+}
+
+fn ascii_lowercase(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?.apply_to_buffer(|v, buf| {
+        buf.push_str(v);
+        buf.make_ascii_lowercase();
+    });
+    Ok(ca.into_series())
+}
+
+/// Gather the output values from `default` followed by `new`.
+///
+/// This only has to materialize the positions that changed and avoids branching per element
+/// on whether a row matched; if no row matched, `default` is returned as is.
+fn gather_replaced(
+    matches: &[Option<IdxSize>],
+    new: &Series,
+    default: Series,
+) -> PolarsResult<Series> {
+    let len = matches.len();
+    if matches.iter().all(|m| m.is_none()) {
+        return default.broadcast_to(len);
+    }
+
+    let default_len = default.len() as IdxSize;
+    let broadcast_default = default.len() != len;
+    let broadcast_new = new.len() == 1;
+    let idx = matches
+        .iter()
+        .enumerate()
+        .map(|(i, m)| match m {
+            Some(_) if broadcast_new => default_len,
+            Some(j) => default_len + *j,
+            None if broadcast_default => 0,
+            None => i as IdxSize,
+        })
+        .collect::<Vec<_>>();
+    let idx = IdxCa::from_vec("", idx);
+
+    let mut values = default;
+    values.append(new)?;
+    values.take(&idx)
+}
+
+/// For every value in `s`, get the position of that value in `old`, if any.
+fn get_replacement_indices(s: &Series, old: &Series) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let s = s.to_physical_repr();
+    let old = old.to_physical_repr();
+    let s: &Series = &s;
+
+    match s.dtype() {
+        DataType::Boolean => get_replacement_indices_helper(s.bool().unwrap(), &old),
+        DataType::Utf8 => get_replacement_indices_helper(s.utf8().unwrap(), &old),
+        DataType::Binary => get_replacement_indices_helper(s.binary().unwrap(), &old),
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                get_replacement_indices_helper(ca, &old)
+            })
+        },
+        dt => polars_bail!(opq = replace, dt),
+    }
+}
+
+fn get_replacement_indices_helper<'a, T>(
+    ca: &'a ChunkedArray<T>,
+    old: &Series,
+) -> PolarsResult<Vec<Option<IdxSize>>>
+where
+    T: PolarsDataType,
+    T::Physical<'a>: TotalHash + TotalEq + Copy,
+{
+    let old = ca.unpack_series_matching_type(old)?;
+
+    // Nulls in `old` are valid keys, they replace the nulls in `ca`.
+    let mut map = PlHashMap::with_capacity(old.len());
+    for (idx, opt_v) in old.downcast_iter().flat_map(|arr| arr.iter()).enumerate() {
+        let duplicate = map.insert(TotalOrdWrap(opt_v), idx as IdxSize).is_some();
+        polars_ensure!(
+            !duplicate,
+            InvalidOperation: "`old` input for `replace` must not contain duplicates"
+        );
+    }
+
+    Ok(ca
+        .downcast_iter()
+        .flat_map(|arr| arr.iter())
+        .map(|opt_v| map.get(&TotalOrdWrap(opt_v)).copied())
+        .collect())
+}
+
+/// Replace every value by the value that belongs to the nearest key.
+///
+/// Every value in `s` is mapped to the value of `values` at the position of the closest key in
+/// `keys`; if a value lies exactly halfway between two keys, the lower key wins. `keys` does not
+/// have to be sorted. Nulls and NaNs in `s` map to null, as does everything if `keys` is empty.
+pub fn replace_nearest(s: &Series, keys: &Series, values: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        keys.len() == values.len(),
+        ShapeMismatch: "`values` input for `replace_nearest` must have the same length as `keys`"
+    );
+    polars_ensure!(
+        keys.null_count() == 0,
+        InvalidOperation: "`keys` input for `replace_nearest` must not contain nulls"
+    );
+    for dt in [s.dtype(), keys.dtype()] {
+        polars_ensure!(dt.is_numeric(), opq = replace_nearest, dt);
+    }
+
+    let keys = keys.cast_with_context(&DataType::Float64, "keys", "replace_nearest", false)?;
+    let order = keys.arg_sort(SortOptions::default());
+    let sorted_keys = keys.f64().unwrap().take(&order)?.rechunk();
+    let sorted_keys = sorted_keys.cont_slice().unwrap();
+    let order = order.into_no_null_iter().collect::<Vec<_>>();
+
+    // NaN keys are sorted last and can never be the nearest key.
+    let sorted_keys = &sorted_keys[..sorted_keys.partition_point(|k| !k.is_nan())];
+    polars_ensure!(
+        sorted_keys.windows(2).all(|w| w[0] != w[1]),
+        InvalidOperation: "`keys` input for `replace_nearest` must not contain duplicates"
+    );
+
+    let s_float = s.cast(&DataType::Float64)?;
+    let idx = s_float
+        .f64()
+        .unwrap()
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v.filter(|v| !v.is_nan() && !sorted_keys.is_empty())?;
+            Some(order[nearest_key(sorted_keys, v)])
+        })
+        .collect::<IdxCa>();
+
+    let mut out = values.take(&idx)?;
+    out.rename(s.name());
+    Ok(out)
+}
+
+/// Position of the key in the non-empty, sorted `keys` that is closest to `v`.
+fn nearest_key(keys: &[f64], v: f64) -> usize {
+    let i = partition_point_total(keys, &v);
+    if i == 0 {
+        0
+    } else if i == keys.len() {
+        i - 1
+    } else if v - keys[i - 1] <= keys[i] - v {
+        // Ties go to the lower key.
+        i - 1
+    } else {
+        i
+    }
+}
+
+/// Replace the values that lie in a range by the value that belongs to that range.
+///
+/// Every value in `s` that lies in one of the half-open `ranges` `[start, end)` is replaced by
+/// the value of `values` at the position of that range. All other values, including nulls and
+/// NaNs, are taken from `default`, which is either a unit-length series or has the same length
+/// as `s`. The ranges must not overlap and `values` must have the same length as `ranges` or
+/// length 1. The output dtype is the supertype of `values` and `default`.
+pub fn replace_ranges(
+    s: &Series,
+    ranges: &[(f64, f64)],
+    values: &Series,
+    default: &Series,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == ranges.len() || values.len() == 1,
+        InvalidOperation: "`values` input for `replace_ranges` must have the same length as `ranges` or have length 1"
+    );
+    polars_ensure!(
+        default.len() == s.len() || default.len() == 1,
+        InvalidOperation: "`default` input for `replace_ranges` must have the same length as the input or have length 1"
+    );
+    polars_ensure!(s.dtype().is_numeric(), opq = replace_ranges, s.dtype());
+    polars_ensure!(
+        ranges.iter().all(|(start, end)| start <= end),
+        InvalidOperation: "every range of `replace_ranges` must have a `start` that is not after its `end`"
+    );
+
+    let mut order = (0..ranges.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| ranges[*a].0.tot_cmp(&ranges[*b].0));
+    let sorted = order.iter().map(|i| ranges[*i]).collect::<Vec<_>>();
+    polars_ensure!(
+        sorted.windows(2).all(|w| w[0].1 <= w[1].0),
+        InvalidOperation: "the ranges of `replace_ranges` must not overlap"
+    );
+
+    let return_dtype = try_get_supertype(values.dtype(), default.dtype())?;
+    let values = values.cast_with_context(&return_dtype, "values", "replace_ranges", false)?;
+    let default = default.cast_with_context(&return_dtype, "default", "replace_ranges", false)?;
+
+    let s_float = s.cast(&DataType::Float64)?;
+    let matches = s_float
+        .f64()
+        .unwrap()
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v?;
+            // The last range that starts at or before `v` is the only one that can hold it.
+            let i = sorted
+                .partition_point(|(start, _)| *start <= v)
+                .checked_sub(1)?;
+            (v < sorted[i].1).then_some(order[i] as IdxSize)
+        })
+        .collect::<Vec<_>>();
+    let mut out = gather_replaced(&matches, &values, default)?;
+    out.rename(s.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(2)]);
+        let old = Series::new("old", &[Some(2), None]);
+        let new = Series::new("new", &[20, 0]);
+
+        let out = replace(&s, &old, &new, &s, None, false)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(20), Some(0), Some(3), Some(20)]
+        );
+
+        let default = Series::new("default", &[-1]);
+        let out = replace(&s, &old, &new, &default, None, false)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(-1), Some(20), Some(0), Some(-1), Some(20)]
+        );
+
+        let new = Series::new("new", &[7i64]);
+        let out = replace(&s, &old, &new, &s, None, false)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(1), Some(7), Some(7), Some(3), Some(7)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_ascii_case_insensitive() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some("Yes"), Some("YES"), Some("yes"), Some("No"), None],
+        );
+        let old = Series::new("old", &["yes"]);
+        let new = Series::new("new", &["y"]);
+
+        let out = replace(&s, &old, &new, &s, None, true)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("y"), Some("y"), Some("y"), Some("No"), None]
+        );
+        let out = replace(&s, &old, &new, &s, None, false)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("Yes"), Some("YES"), Some("y"), Some("No"), None]
+        );
+
+        // Keys that only differ in case are duplicates.
+        let old = Series::new("old", &["yes", "YES"]);
+        let new = Series::new("new", &["y", "Y"]);
+        assert!(replace(&s, &old, &new, &s, None, true).is_err());
+
+        let s = Series::new("a", &[1, 2]);
+        let old = Series::new("old", &[1]);
+        let new = Series::new("new", &[10]);
+        assert!(replace(&s, &old, &new, &s, None, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_few_matches() -> PolarsResult<()> {
+        let s = Series::new("a", (0..1000).collect::<Vec<i64>>());
+        let old = Series::new("old", (0..1000).step_by(100).collect::<Vec<i64>>());
+        let new = Series::new("new", vec![-1i64; old.len()]);
+
+        let out = replace(&s, &old, &new, &s, None, false)?;
+        let expected = (0..1000)
+            .map(|v| if v % 100 == 0 { -1 } else { v })
+            .collect::<Vec<i64>>();
+        assert_eq!(
+            out.i64()?.to_vec(),
+            expected.into_iter().map(Some).collect::<Vec<_>>()
+        );
+
+        // Nothing matches, so the input is passed through untouched.
+        let old = Series::new("old", &[-5i64]);
+        let new = Series::new("new", &[5i64]);
+        let out = replace(&s, &old, &new, &s, None, false)?;
+        assert!(out.equals(&s));
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_invalid() {
+        let s = Series::new("a", &["x", "y"]);
+        let old = Series::new("old", &["x", "x"]);
+        let new = Series::new("new", &["a", "b"]);
+        assert!(replace(&s, &old, &new, &s, None, false).is_err());
+
+        let old = Series::new("old", &["x", "y"]);
+        let new = Series::new("new", &["a", "b", "c"]);
+        assert!(replace(&s, &old, &new, &s, None, false).is_err());
+    }
+
+    #[test]
+    fn test_replace_cast_error_context() {
+        let s = Series::new("a", &[1i64, 2]);
+        let old = Series::new("old", &["1", "x"]);
+        let new = Series::new("new", &[10i64]);
+        let err = replace(&s, &old, &new, &s, None, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`old`"), "{err}");
+        assert!(err.contains("`replace`"), "{err}");
+        // The value that failed to convert is listed.
+        assert!(err.contains("x"), "{err}");
+    }
+
+    #[test]
+    fn test_replace_eager() -> PolarsResult<()> {
+        use crate::series::SeriesMethods;
+
+        let s = Series::new("a", &[Some(1i64), Some(2), None, Some(3)]);
+        let old = Series::new("old", &[1i64, 2]);
+        let new = Series::new("new", &[10i64, 20]);
+
+        let out = s.replace(&old, &new)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(10), Some(20), None, Some(3)]);
+
+        // Strict without a default fails on the unmatched 3.
+        assert!(s.replace_strict(&old, &new, None, None).is_err());
+
+        let old = Series::new("old", &[1i64, 2, 3]);
+        let new = Series::new("new", &["x", "y", "z"]);
+        let out = s.replace_strict(&old, &new, None, None)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("x"), Some("y"), None, Some("z")]
+        );
+
+        let old = Series::new("old", &[1i64]);
+        let new = Series::new("new", &[1.5]);
+        let default = Series::new("default", &[0i32]);
+        let out = s.replace_strict(&old, &new, Some(&default), Some(DataType::Float32))?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_eq!(
+            Vec::from(out.f32()?),
+            &[Some(1.5), Some(0.0), Some(0.0), Some(0.0)]
+        );
+
+        let empty = Series::new_empty("old", &DataType::Int64);
+        assert!(s.replace_strict(&empty, &empty, None, None).is_err());
+        let nulls = Series::full_null("a", 2, &DataType::Int64);
+        let out = nulls.replace_strict(&empty, &empty, None, None)?;
+        assert_eq!(out.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_replace_field() -> PolarsResult<()> {
+        let a = Series::new("a", &[1i64, 2, 3]);
+        let b = Series::new("b", &[1i64, 2, 3]);
+        let s = StructChunked::new("s", &[a, b.clone()])?.into_series();
+        let old = Series::new("old", &[1i64, 3]);
+        let new = Series::new("new", &[10i64, 30]);
+
+        let out = replace_field(&s, "a", &old, &new, None)?;
+        assert_eq!(out.name(), "s");
+        let out = out.struct_()?;
+        assert_eq!(
+            Vec::from(out.field_by_name("a")?.i64()?),
+            &[Some(10), Some(2), Some(30)]
+        );
+        assert!(out.field_by_name("b")?.equals(&b));
+
+        let default = Series::new("default", &[0i64]);
+        let out = replace_field(&s, "a", &old, &new, Some(&default))?;
+        assert_eq!(
+            Vec::from(out.struct_()?.field_by_name("a")?.i64()?),
+            &[Some(10), Some(0), Some(30)]
+        );
+
+        assert!(replace_field(&s, "c", &old, &new, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_nearest() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(-3.2),
+                Some(0.4),
+                Some(0.5),
+                None,
+                Some(1.51),
+                Some(2.0),
+                Some(f64::NAN),
+                Some(9.0),
+            ],
+        );
+        let keys = Series::new("keys", &[1.0, 0.0, 2.0]);
+        let values = Series::new("values", &["one", "zero", "two"]);
+
+        let out = replace_nearest(&s, &keys, &values)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("zero"),
+                Some("zero"),
+                Some("zero"),
+                None,
+                Some("two"),
+                Some("two"),
+                None,
+                Some("two")
+            ]
+        );
+
+        let keys = Series::new("keys", &[1.0, 1.0, 2.0]);
+        assert!(replace_nearest(&s, &keys, &values).is_err());
+        let values = Series::new("values", &["one"]);
+        assert!(replace_nearest(&s, &keys, &values).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_ranges() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(5),
+                Some(10),
+                Some(19),
+                Some(20),
+                None,
+                Some(35),
+                Some(40),
+            ],
+        );
+        let values = Series::new("values", &[2, 1]);
+        let default = Series::new("default", &[0]);
+        let out = replace_ranges(&s, &[(30.0, 40.0), (10.0, 20.0)], &values, &default)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(0),
+                Some(0),
+                Some(2),
+                Some(0)
+            ]
+        );
+
+        // Keep the values outside of the ranges.
+        let out = replace_ranges(&s, &[(10.0, 20.0), (30.0, 40.0)], &values, &s)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(5), Some(2), Some(2), Some(20), None, Some(1), Some(40)]
+        );
+
+        assert!(replace_ranges(&s, &[(10.0, 20.0), (15.0, 40.0)], &values, &s).is_err());
+        assert!(replace_ranges(&s, &[(20.0, 10.0)], &default, &s).is_err());
+        Ok(())
+    }
+}
@@ -29,6 +29,68 @@ pub trait SeriesMethods: SeriesSealed {
         }
     }
 
+    /// Return a new [`Series`] with the values at `indices` replaced by the corresponding
+    /// values of `values`. `values` must have the same length as `indices` or length 1, in
+    /// which case it is broadcast.
+    fn set_at_indices(&self, indices: &IdxCa, values: &Series) -> PolarsResult<Series> {
+        crate::chunked_array::set_at_indices(self.as_series(), indices, values)
+    }
+
+    /// Replace the values found in `old` by the value of `new` at the same position and keep
+    /// all other values. See [`replace`](crate::series::replace).
+    #[cfg(feature = "replace")]
+    fn replace(&self, old: &Series, new: &Series) -> PolarsResult<Series> {
+        let s = self.as_series();
+        crate::series::replace(s, old, new, s, None, false)
+    }
+
+    /// Replace the values found in `old` by the value of `new` at the same position. Values
+    /// that are not found are set to `default`, or raise an error if no `default` is given.
+    /// See [`replace_strict`](crate::series::replace_strict).
+    #[cfg(feature = "replace")]
+    fn replace_strict(
+        &self,
+        old: &Series,
+        new: &Series,
+        default: Option<&Series>,
+        return_dtype: Option<DataType>,
+    ) -> PolarsResult<Series> {
+        crate::series::replace_strict(self.as_series(), old, new, default, return_dtype)
+    }
+
+    /// The index of the power of two bucket of every value of this [`Series`]. See
+    /// [`power_of_two_bucket`](crate::series::power_of_two_bucket).
+    fn power_of_two_bucket(&self, num_buckets: u32) -> PolarsResult<Series> {
+        crate::series::power_of_two_bucket(self.as_series(), num_buckets)
+    }
+
+    /// The Levenshtein distance between every pair of strings of this [`Series`]. See
+    /// [`pairwise_levenshtein`](crate::series::pairwise_levenshtein).
+    fn pairwise_levenshtein(&self, max_dist: Option<usize>) -> PolarsResult<DataFrame> {
+        crate::series::pairwise_levenshtein(self.as_series(), max_dist)
+    }
+
+    /// The number of distinct values up to and including every row. See
+    /// [`cumulative_unique_count`](crate::series::cumulative_unique_count).
+    #[cfg(feature = "cum_agg")]
+    fn cumulative_unique_count(&self) -> PolarsResult<Series> {
+        crate::series::cumulative_unique_count(self.as_series())
+    }
+
+    /// The cumulative integral of this [`Series`] over the sample points `x` by the trapezoidal
+    /// rule. See [`cumulative_trapz`](crate::series::cumulative_trapz).
+    #[cfg(feature = "cum_agg")]
+    fn cumulative_trapz(&self, x: &Series) -> PolarsResult<Series> {
+        crate::series::cumulative_trapz(self.as_series(), Some(x))
+    }
+
+    /// The numerical gradient of this [`Series`] over the sample points `x`, or over unit
+    /// spacing if `x` is `None`. See [`gradient`](crate::series::gradient).
+    #[cfg(feature = "diff")]
+    fn gradient(&self, x: Option<&Series>) -> PolarsResult<Series> {
+        crate::series::gradient(self.as_series(), x)
+    }
+
     #[cfg(feature = "hash")]
     fn hash(&self, build_hasher: ahash::RandomState) -> UInt64Chunked {
         let s = self.as_series().to_physical_repr();
@@ -111,22 +111,9 @@ impl ChainedThen {
         // which will be used in the next layer `outer`
         //
 
-        let conditions_iter = self.conditions.into_iter().rev();
-        let mut statements_iter = self.statements.into_iter().rev();
-
-        let mut otherwise = expr.into();
-
-        for e in conditions_iter {
-            otherwise = ternary_expr(
-                e,
-                statements_iter
-                    .next()
-                    .expect("expr expected, did you call when().then().otherwise?"),
-                otherwise,
-            );
-        }
-
-        otherwise
+        debug_assert_eq!(self.conditions.len(), self.statements.len());
+        let branches = self.conditions.into_iter().zip(self.statements).collect();
+        when_then_many(branches, expr.into())
     }
 }
 
@@ -137,6 +124,25 @@ pub fn when<E: Into<Expr>>(condition: E) -> When {
     }
 }
 
+/// Build a `when-then-otherwise` expression from `(condition, value)` branches.
+///
+/// The value of the first branch of which the condition is true is taken, and `otherwise` if
+/// there is none. This is equivalent to chaining `when(..).then(..)` for every branch, without
+/// the intermediate builder states, so it is the way to construct a chain of which the length
+/// is only known at runtime. The values are cast to their common supertype.
+///
+/// The result is not a single flat expression: it is one nested ternary per branch, as the
+/// chained builder produces. Lowering it to e.g. a `coalesce` would take the wrong branch when
+/// the value of a matching branch is null.
+pub fn when_then_many(branches: Vec<(Expr, Expr)>, otherwise: Expr) -> Expr {
+    branches
+        .into_iter()
+        .rev()
+        .fold(otherwise, |otherwise, (condition, value)| {
+            ternary_expr(condition, value, otherwise)
+        })
+}
+
 pub fn ternary_expr(predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
     Expr::Ternary {
         predicate: Box::new(predicate),
@@ -205,6 +205,18 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::DSTOffset))
     }
 
+    /// Get the DST offset in minutes that `time_zone` applies at every UTC timestamp: 0 in
+    /// standard time and usually 60 in daylight saving time. Unlike [`dst_offset`], the time zone
+    /// of the column is ignored.
+    ///
+    /// [`dst_offset`]: Self::dst_offset
+    #[cfg(feature = "timezones")]
+    pub fn dst_offset_minutes(self, time_zone: &str) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::DSTOffsetMinutes(time_zone.to_string()),
+        ))
+    }
+
     pub fn round<S: AsRef<str>>(self, every: S, offset: S) -> Expr {
         let every = every.as_ref().into();
         let offset = offset.as_ref().into();
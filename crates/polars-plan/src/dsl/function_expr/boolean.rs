@@ -30,8 +30,12 @@ pub enum BooleanFunction {
     IsDuplicated,
     #[cfg(feature = "is_in")]
     IsIn,
-    AllHorizontal,
-    AnyHorizontal,
+    AllHorizontal {
+        strict: bool,
+    },
+    AnyHorizontal {
+        strict: bool,
+    },
     Not,
 }
 
@@ -39,8 +43,8 @@ impl BooleanFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         use BooleanFunction::*;
         match self {
-            AllHorizontal => Ok(Field::new("all", DataType::Boolean)),
-            AnyHorizontal => Ok(Field::new("any", DataType::Boolean)),
+            AllHorizontal { .. } => Ok(Field::new("all", DataType::Boolean)),
+            AnyHorizontal { .. } => Ok(Field::new("any", DataType::Boolean)),
             _ => mapper.with_dtype(DataType::Boolean),
         }
     }
@@ -68,8 +72,8 @@ impl Display for BooleanFunction {
             IsDuplicated => "is_duplicated",
             #[cfg(feature = "is_in")]
             IsIn => "is_in",
-            AnyHorizontal => "any_horizontal",
-            AllHorizontal => "all_horizontal",
+            AnyHorizontal { .. } => "any_horizontal",
+            AllHorizontal { .. } => "all_horizontal",
             Not => "not",
         };
         write!(f, "{s}")
@@ -98,8 +102,8 @@ impl From<BooleanFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             IsDuplicated => map!(is_duplicated),
             #[cfg(feature = "is_in")]
             IsIn => wrap!(is_in),
-            AllHorizontal => map_as_slice!(all_horizontal),
-            AnyHorizontal => map_as_slice!(any_horizontal),
+            AllHorizontal { strict } => map_as_slice!(all_horizontal, strict),
+            AnyHorizontal { strict } => map_as_slice!(any_horizontal, strict),
             Not => map!(not),
         }
     }
@@ -180,14 +184,31 @@ fn is_in(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::is_in(left, other).map(|ca| Some(ca.into_series()))
 }
 
-fn any_horizontal(s: &[Series]) -> PolarsResult<Series> {
+fn any_horizontal(s: &[Series], strict: bool) -> PolarsResult<Series> {
+    if strict {
+        ensure_boolean_inputs("any_horizontal_strict", s)?;
+    }
     polars_ops::prelude::any_horizontal(s)
 }
 
-fn all_horizontal(s: &[Series]) -> PolarsResult<Series> {
+fn all_horizontal(s: &[Series], strict: bool) -> PolarsResult<Series> {
+    if strict {
+        ensure_boolean_inputs("all_horizontal_strict", s)?;
+    }
     polars_ops::prelude::all_horizontal(s)
 }
 
+fn ensure_boolean_inputs(function_name: &str, s: &[Series]) -> PolarsResult<()> {
+    for s in s {
+        polars_ensure!(
+            s.dtype() == &DataType::Boolean,
+            InvalidOperation: "`{}` expects boolean inputs, got column '{}' of dtype {}",
+            function_name, s.name(), s.dtype()
+        );
+    }
+    Ok(())
+}
+
 fn not(s: &Series) -> PolarsResult<Series> {
     Ok(s.bool()?.not().into_series())
 }
@@ -4,6 +4,8 @@ use chrono_tz::Tz;
 use polars_time::base_utc_offset as base_utc_offset_fn;
 #[cfg(feature = "timezones")]
 use polars_time::dst_offset as dst_offset_fn;
+#[cfg(feature = "timezones")]
+use polars_time::dst_offset_minutes as dst_offset_minutes_fn;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +47,8 @@ pub enum TemporalFunction {
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
     DSTOffset,
+    #[cfg(feature = "timezones")]
+    DSTOffsetMinutes(TimeZone),
     Round(String, String),
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>),
@@ -94,6 +98,8 @@ impl TemporalFunction {
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
             DSTOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
+            #[cfg(feature = "timezones")]
+            DSTOffsetMinutes(_) => mapper.with_dtype(DataType::Int32),
             Round(..) => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
@@ -152,6 +158,8 @@ impl Display for TemporalFunction {
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
             DSTOffset => "dst_offset",
+            #[cfg(feature = "timezones")]
+            DSTOffsetMinutes(_) => "dst_offset_minutes",
             Round(..) => "round",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_) => "replace_time_zone",
@@ -411,6 +419,18 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
         ),
     }
 }
+#[cfg(feature = "timezones")]
+pub(super) fn dst_offset_minutes(s: &Series, time_zone: &str) -> PolarsResult<Series> {
+    let tz = time_zone
+        .parse::<Tz>()
+        .map_err(|_| polars_err!(ComputeError: "unable to parse time zone: '{}'", time_zone))?;
+    match s.dtype() {
+        DataType::Datetime(time_unit, _) => {
+            Ok(dst_offset_minutes_fn(s.datetime().unwrap(), time_unit, &tz).into_series())
+        },
+        dt => polars_bail!(opq = dst_offset_minutes, got = dt, expected = "datetime"),
+    }
+}
 
 pub(super) fn round(s: &[Series], every: &str, offset: &str) -> PolarsResult<Series> {
     let every = Duration::parse(every);
@@ -61,6 +61,8 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
 mod unique;
+#[cfg(feature = "range")]
+mod validate;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
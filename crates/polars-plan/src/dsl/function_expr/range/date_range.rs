@@ -1,10 +1,12 @@
 use polars_core::prelude::*;
 use polars_core::series::Series;
-use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
-use polars_time::{datetime_range_impl, ClosedWindow, Duration};
+use polars_time::{date_range_impl, ClosedWindow, Duration};
 
 use super::datetime_range::{datetime_range, datetime_ranges};
-use super::utils::{ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar};
+use super::utils::{
+    ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar, validate_inputs,
+    TEMPORAL_RANGE_ARGS,
+};
 use crate::dsl::function_expr::FieldsMapper;
 
 const CAPACITY_FACTOR: usize = 5;
@@ -16,6 +18,7 @@ pub(super) fn temporal_range(
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
 ) -> PolarsResult<Series> {
+    validate_inputs("date_range", s, TEMPORAL_RANGE_ARGS)?;
     if s[0].dtype() == &DataType::Date && interval.is_full_days() {
         date_range(s, interval, closed)
     } else {
@@ -32,6 +35,7 @@ pub(super) fn temporal_ranges(
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
 ) -> PolarsResult<Series> {
+    validate_inputs("date_ranges", s, TEMPORAL_RANGE_ARGS)?;
     if s[0].dtype() == &DataType::Date && interval.is_full_days() {
         date_ranges(s, interval, closed)
     } else {
@@ -47,25 +51,12 @@ fn date_range(s: &[Series], interval: Duration, closed: ClosedWindow) -> PolarsR
 
     ensure_range_bounds_contain_exactly_one_value(start, end)?;
 
-    let dtype = DataType::Date;
-    let start = temporal_series_to_i64_scalar(start)
-        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?
-        * MILLISECONDS_IN_DAY;
-    let end = temporal_series_to_i64_scalar(end)
-        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?
-        * MILLISECONDS_IN_DAY;
-
-    let result = datetime_range_impl(
-        "date",
-        start,
-        end,
-        interval,
-        closed,
-        TimeUnit::Milliseconds,
-        None,
-    )?
-    .cast(&dtype)?;
+    let start = temporal_series_to_i64_scalar(start)?
+        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
+    let end = temporal_series_to_i64_scalar(end)?
+        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
 
+    let result = date_range_impl("date", start as i32, end as i32, interval, closed)?;
     Ok(result.into_series())
 }
 
@@ -78,45 +69,27 @@ fn date_ranges(s: &[Series], interval: Duration, closed: ClosedWindow) -> Polars
         ComputeError: "`start` and `end` must have the same length",
     );
 
-    let start = date_series_to_i64_ca(start)? * MILLISECONDS_IN_DAY;
-    let end = date_series_to_i64_ca(end)? * MILLISECONDS_IN_DAY;
+    let start = start.cast(&DataType::Int32)?;
+    let start = start.i32().unwrap();
+    let end = end.cast(&DataType::Int32)?;
+    let end = end.i32().unwrap();
 
     let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
         "date_range",
         start.len(),
         start.len() * CAPACITY_FACTOR,
-        DataType::Int32,
+        DataType::Date,
     );
-    for (start, end) in start.as_ref().into_iter().zip(&end) {
+    for (start, end) in start.into_iter().zip(end) {
         match (start, end) {
             (Some(start), Some(end)) => {
-                // TODO: Implement an i32 version of `date_range_impl`
-                let rng = datetime_range_impl(
-                    "",
-                    start,
-                    end,
-                    interval,
-                    closed,
-                    TimeUnit::Milliseconds,
-                    None,
-                )?;
-                let rng = rng.cast(&DataType::Date).unwrap();
-                let rng = rng.to_physical_repr();
-                let rng = rng.i32().unwrap();
+                let rng = date_range_impl("", start, end, interval, closed)?;
                 builder.append_slice(rng.cont_slice().unwrap())
             },
             _ => builder.append_null(),
         }
     }
-    let list = builder.finish().into_series();
-
-    let to_type = DataType::List(Box::new(DataType::Date));
-    list.cast(&to_type)
-}
-fn date_series_to_i64_ca(s: &Series) -> PolarsResult<ChunkedArray<Int64Type>> {
-    let s = s.cast(&DataType::Int64)?;
-    let result = s.i64().unwrap();
-    Ok(result.clone())
+    Ok(builder.finish().into_series())
 }
 
 impl<'a> FieldsMapper<'a> {
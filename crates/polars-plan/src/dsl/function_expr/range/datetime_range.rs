@@ -2,7 +2,10 @@ use polars_core::prelude::*;
 use polars_core::series::Series;
 use polars_time::{datetime_range_impl, ClosedWindow, Duration};
 
-use super::utils::{ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar};
+use super::utils::{
+    ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar_in_unit,
+    validate_inputs, TEMPORAL_RANGE_ARGS,
+};
 use crate::dsl::function_expr::FieldsMapper;
 
 const CAPACITY_FACTOR: usize = 5;
@@ -14,6 +17,7 @@ pub(super) fn datetime_range(
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
 ) -> PolarsResult<Series> {
+    validate_inputs("datetime_range", s, TEMPORAL_RANGE_ARGS)?;
     let start = &s[0];
     let end = &s[1];
 
@@ -39,24 +43,15 @@ pub(super) fn datetime_range(
         _ => unreachable!(),
     };
 
-    let (start, end) = match dtype {
-        #[cfg(feature = "timezones")]
-        DataType::Datetime(_, Some(_)) => (
-            polars_ops::prelude::replace_time_zone(
-                start.cast(&dtype)?.datetime().unwrap(),
-                None,
-                &Utf8Chunked::from_iter(std::iter::once("raise")),
-            )?
-            .into_series(),
-            polars_ops::prelude::replace_time_zone(
-                end.cast(&dtype)?.datetime().unwrap(),
-                None,
-                &Utf8Chunked::from_iter(std::iter::once("raise")),
-            )?
-            .into_series(),
-        ),
-        _ => (start.cast(&dtype)?, end.cast(&dtype)?),
+    let DataType::Datetime(tu, _) = dtype else {
+        unreachable!()
     };
+    let start = start.cast_with_context(&dtype, "start", "datetime_range", false)?;
+    let end = end.cast_with_context(&dtype, "end", "datetime_range", false)?;
+    let start = temporal_series_to_i64_scalar_in_unit(&start, tu, false)?
+        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
+    let end = temporal_series_to_i64_scalar_in_unit(&end, tu, false)?
+        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
 
     // overwrite time zone, if specified
     match (&dtype, &time_zone) {
@@ -67,11 +62,6 @@ pub(super) fn datetime_range(
         _ => {},
     };
 
-    let start = temporal_series_to_i64_scalar(&start)
-        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
-    let end = temporal_series_to_i64_scalar(&end)
-        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
-
     let result = match dtype {
         DataType::Datetime(tu, ref tz) => {
             datetime_range_impl("datetime", start, end, interval, closed, tu, tz.as_ref())?
@@ -88,6 +78,7 @@ pub(super) fn datetime_ranges(
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
 ) -> PolarsResult<Series> {
+    validate_inputs("datetime_ranges", s, TEMPORAL_RANGE_ARGS)?;
     let start = &s[0];
     let end = &s[1];
 
@@ -165,7 +156,7 @@ pub(super) fn datetime_ranges(
                 "datetime_range",
                 start.len(),
                 start.len() * CAPACITY_FACTOR,
-                DataType::Int64,
+                dtype.clone(),
             );
             for (start, end) in start.into_iter().zip(end) {
                 match (start, end) {
@@ -181,9 +172,7 @@ pub(super) fn datetime_ranges(
         },
         _ => unimplemented!(),
     };
-
-    let to_type = DataType::List(Box::new(dtype));
-    list.cast(&to_type)
+    Ok(list)
 }
 
 impl<'a> FieldsMapper<'a> {
@@ -1,9 +1,12 @@
 use polars_core::prelude::*;
 use polars_core::series::{IsSorted, Series};
 
-use super::utils::ensure_range_bounds_contain_exactly_one_value;
+use super::utils::{
+    ensure_range_bounds_contain_exactly_one_value, validate_inputs, INT_RANGE_ARGS,
+};
 
 pub(super) fn int_range(s: &[Series], step: i64) -> PolarsResult<Series> {
+    validate_inputs("int_range", s, INT_RANGE_ARGS)?;
     let start = &s[0];
     let end = &s[1];
 
@@ -11,35 +14,40 @@ pub(super) fn int_range(s: &[Series], step: i64) -> PolarsResult<Series> {
 
     match start.dtype() {
         dt if dt == &IDX_DTYPE => {
-            let start = start.idx()?.get(0).unwrap();
-            let end = end.cast(&IDX_DTYPE)?;
-            let end = end.idx()?.get(0).unwrap();
+            let start = range_bound(start.idx()?.get_scalar()?, "start")?;
+            let end = end.cast_with_context(&IDX_DTYPE, "end", "int_range", true)?;
+            let end = range_bound(end.idx()?.get_scalar()?, "end")?;
             int_range_impl::<IdxType>(start, end, step)
         },
         _ => {
-            let start = start.cast(&DataType::Int64)?;
-            let end = end.cast(&DataType::Int64)?;
-            let start = start.i64()?.get(0).unwrap();
-            let end = end.i64()?.get(0).unwrap();
+            let start = start.cast_with_context(&DataType::Int64, "start", "int_range", true)?;
+            let end = end.cast_with_context(&DataType::Int64, "end", "int_range", true)?;
+            let start = range_bound(start.i64()?.get_scalar()?, "start")?;
+            let end = range_bound(end.i64()?.get_scalar()?, "end")?;
             int_range_impl::<Int64Type>(start, end, step)
         },
     }
 }
 
+fn range_bound<T>(value: Option<T>, name: &str) -> PolarsResult<T> {
+    value.ok_or_else(|| polars_err!(ComputeError: "`{}` of `int_range` must not be null", name))
+}
+
 pub(super) fn int_ranges(s: &[Series], step: i64) -> PolarsResult<Series> {
+    validate_inputs("int_ranges", s, INT_RANGE_ARGS)?;
     let start = &s[0].rechunk();
     let end = &s[1].rechunk();
 
     let output_name = "int_range";
 
-    let mut start = start.cast(&DataType::Int64)?;
-    let mut end = end.cast(&DataType::Int64)?;
+    let mut start = start.cast_with_context(&DataType::Int64, "start", "int_ranges", false)?;
+    let mut end = end.cast_with_context(&DataType::Int64, "end", "int_ranges", false)?;
 
     if start.len() != end.len() {
         if start.len() == 1 {
-            start = start.new_from_index(0, end.len())
+            start = start.broadcast_to(end.len())?
         } else if end.len() == 1 {
-            end = end.new_from_index(0, start.len())
+            end = end.broadcast_to(start.len())?
         } else {
             polars_bail!(
                 ComputeError:
@@ -1,26 +1,41 @@
 use polars_core::prelude::*;
 use polars_core::series::Series;
-use polars_time::{time_range_impl, ClosedWindow, Duration};
+use polars_ops::prelude::ListNameSpaceImpl;
+use polars_time::{time_range_impl, time_range_iter, ClosedWindow, Duration};
 
-use super::utils::{ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar};
-
-const CAPACITY_FACTOR: usize = 5;
+use super::utils::{
+    ensure_range_bounds_contain_exactly_one_value, temporal_series_to_i64_scalar, validate_inputs,
+    TIME_RANGE_ARGS,
+};
 
 pub(super) fn time_range(
     s: &[Series],
     interval: Duration,
     closed: ClosedWindow,
 ) -> PolarsResult<Series> {
+    validate_inputs("time_range", s, TIME_RANGE_ARGS)?;
     let start = &s[0];
     let end = &s[1];
 
     ensure_range_bounds_contain_exactly_one_value(start, end)?;
 
     let dtype = DataType::Time;
-    let start = temporal_series_to_i64_scalar(&start.cast(&dtype)?)
-        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
-    let end = temporal_series_to_i64_scalar(&end.cast(&dtype)?)
-        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
+    let start = temporal_series_to_i64_scalar(&start.cast_with_context(
+        &dtype,
+        "start",
+        "time_range",
+        false,
+    )?)?;
+    let end = temporal_series_to_i64_scalar(&end.cast_with_context(
+        &dtype,
+        "end",
+        "time_range",
+        false,
+    )?)?;
+    // A null bound, e.g. the minimum of an empty group, gives an empty range.
+    let (Some(start), Some(end)) = (start, end) else {
+        return Ok(Series::new_empty("time", &dtype));
+    };
 
     let out = time_range_impl("time", start, end, interval, closed)?;
     Ok(out.cast(&dtype).unwrap().into_series())
@@ -31,8 +46,14 @@ pub(super) fn time_ranges(
     interval: Duration,
     closed: ClosedWindow,
 ) -> PolarsResult<Series> {
-    let start = &s[0];
-    let end = &s[1];
+    let (start, end) = match s {
+        [bounds] => unpack_bounds(bounds)?,
+        _ => {
+            validate_inputs("time_ranges", s, TIME_RANGE_ARGS)?;
+            (s[0].clone(), s[1].clone())
+        },
+    };
+    let (start, end) = (&start, &end);
 
     polars_ensure!(
         start.len() == end.len(),
@@ -42,26 +63,55 @@ pub(super) fn time_ranges(
     let start = time_series_to_i64_ca(start)?;
     let end = time_series_to_i64_ca(end)?;
 
-    let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new(
-        "time_range",
-        start.len(),
-        start.len() * CAPACITY_FACTOR,
-        DataType::Int64,
-    );
+    // Reserve room for all values up front, the ranges are streamed into the builder.
+    let step = interval.duration_ns();
+    let n_values = if step > 0 {
+        start
+            .into_iter()
+            .zip(&end)
+            .map(|(start, end)| match (start, end) {
+                (Some(start), Some(end)) if end >= start => ((end - start) / step) as usize + 1,
+                _ => 0,
+            })
+            .sum()
+    } else {
+        0
+    };
+    let mut builder =
+        ListPrimitiveChunkedBuilder::<Int64Type>::new("time_range", 0, 0, DataType::Time);
+    builder.reserve(start.len(), n_values);
     for (start, end) in start.as_ref().into_iter().zip(&end) {
         match (start, end) {
             (Some(start), Some(end)) => {
-                let rng = time_range_impl("", start, end, interval, closed)?;
-                builder.append_slice(rng.cont_slice().unwrap())
+                let rng = time_range_iter(start, end, interval, closed)?;
+                builder.append_values_iter(rng)
             },
             _ => builder.append_null(),
         }
     }
-    let list = builder.finish().into_series();
+    Ok(builder.finish().into_series())
+}
 
-    let to_type = DataType::List(Box::new(DataType::Time));
-    list.cast(&to_type)
+/// Split a `List` column of `[start, end]` pairs into a `start` and an `end` column.
+fn unpack_bounds(bounds: &Series) -> PolarsResult<(Series, Series)> {
+    let ca = bounds.list().map_err(|_| {
+        polars_err!(
+            InvalidOperation: "packed time range bounds must be a list of `[start, end]` pairs, got {}",
+            bounds.dtype()
+        )
+    })?;
+    let lengths = ca.lst_lengths();
+    let valid = ca.is_not_null();
+    polars_ensure!(
+        lengths
+            .into_iter()
+            .zip(&valid)
+            .all(|(len, valid)| valid != Some(true) || len == Some(2)),
+        ComputeError: "every row of packed time range bounds must contain exactly two values"
+    );
+    Ok((ca.lst_get(0)?, ca.lst_get(1)?))
 }
+
 fn time_series_to_i64_ca(s: &Series) -> PolarsResult<ChunkedArray<Int64Type>> {
     let s = s.cast(&DataType::Time)?;
     let s = s.to_physical_repr();
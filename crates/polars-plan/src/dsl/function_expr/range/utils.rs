@@ -1,8 +1,107 @@
-use polars_core::prelude::{polars_bail, polars_ensure, PolarsResult};
+use polars_core::prelude::*;
 use polars_core::series::Series;
+#[cfg(feature = "dtype-datetime")]
+use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
 
-pub(super) fn temporal_series_to_i64_scalar(s: &Series) -> Option<i64> {
-    s.to_physical_repr().get(0).unwrap().extract::<i64>()
+pub(super) use crate::dsl::function_expr::validate::{validate_inputs, ArgSpec};
+
+pub(super) const INT_RANGE_ARGS: &[ArgSpec] = &[("start", any_dtype), ("end", any_dtype)];
+#[cfg(any(feature = "temporal", feature = "dtype-datetime"))]
+pub(super) const TEMPORAL_RANGE_ARGS: &[ArgSpec] =
+    &[("start", is_date_or_datetime), ("end", is_date_or_datetime)];
+#[cfg(feature = "dtype-time")]
+pub(super) const TIME_RANGE_ARGS: &[ArgSpec] =
+    &[("start", is_time_or_datetime), ("end", is_time_or_datetime)];
+
+/// The integer range bounds are cast to an integer type, which reports the dtypes that can't be.
+fn any_dtype(_dtype: &DataType) -> bool {
+    true
+}
+
+#[cfg(any(feature = "temporal", feature = "dtype-datetime"))]
+fn is_date_or_datetime(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::Date | DataType::Datetime(_, _))
+}
+
+#[cfg(feature = "dtype-time")]
+fn is_time_or_datetime(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::Time | DataType::Datetime(_, _))
+}
+
+/// Extract the single physical value of a temporal series; errors unless it has length one.
+pub(super) fn temporal_series_to_i64_scalar(s: &Series) -> PolarsResult<Option<i64>> {
+    let s = s.to_physical_repr();
+    match s.dtype() {
+        DataType::Int32 => Ok(s.i32()?.get_scalar()?.map(i64::from)),
+        _ => s.i64()?.get_scalar(),
+    }
+}
+
+/// Extract the single value of a date, datetime or duration series in the time unit `tu`.
+///
+/// Time zone aware datetimes are returned as instants (UTC) if `as_instant` is set and as
+/// wall time in their time zone otherwise. A null value gives `None`.
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn temporal_series_to_i64_scalar_in_unit(
+    s: &Series,
+    tu: TimeUnit,
+    as_instant: bool,
+) -> PolarsResult<Option<i64>> {
+    let (value, src_tu) = match s.dtype() {
+        DataType::Date => {
+            let days = temporal_series_to_i64_scalar(s)?;
+            (
+                days.map(|d| d * MILLISECONDS_IN_DAY),
+                TimeUnit::Milliseconds,
+            )
+        },
+        DataType::Datetime(src_tu, tz) => {
+            let value = match tz {
+                #[cfg(feature = "timezones")]
+                Some(_) if !as_instant => {
+                    let s = polars_ops::prelude::replace_time_zone(
+                        s.datetime().unwrap(),
+                        None,
+                        &Utf8Chunked::from_iter(std::iter::once("raise")),
+                    )?
+                    .into_series();
+                    temporal_series_to_i64_scalar(&s)?
+                },
+                #[cfg(not(feature = "timezones"))]
+                Some(_) if !as_instant => polars_bail!(
+                    ComputeError: "cannot get the wall time of a time zone aware datetime: \
+                    activate the 'timezones' feature"
+                ),
+                _ => temporal_series_to_i64_scalar(s)?,
+            };
+            (value, *src_tu)
+        },
+        DataType::Duration(src_tu) => (temporal_series_to_i64_scalar(s)?, *src_tu),
+        dt => polars_bail!(
+            ComputeError: "expected a date, datetime or duration value, got {}", dt
+        ),
+    };
+    value.map(|v| convert_time_unit(v, src_tu, tu)).transpose()
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn convert_time_unit(v: i64, from: TimeUnit, to: TimeUnit) -> PolarsResult<i64> {
+    let nanoseconds = |tu| match tu {
+        TimeUnit::Nanoseconds => 1i64,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    };
+    let (from_ns, to_ns) = (nanoseconds(from), nanoseconds(to));
+    if from_ns >= to_ns {
+        v.checked_mul(from_ns / to_ns).ok_or_else(|| {
+            polars_err!(
+                ComputeError: "value {} is out of range when converting from {} to {}",
+                v, from, to
+            )
+        })
+    } else {
+        Ok(v / (to_ns / from_ns))
+    }
 }
 
 pub(super) fn ensure_range_bounds_contain_exactly_one_value(
@@ -19,3 +118,102 @@ pub(super) fn ensure_range_bounds_contain_exactly_one_value(
     );
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg(feature = "dtype-datetime")]
+mod test {
+    use super::*;
+
+    const UNITS: [TimeUnit; 3] = [
+        TimeUnit::Nanoseconds,
+        TimeUnit::Microseconds,
+        TimeUnit::Milliseconds,
+    ];
+
+    fn scale(tu: TimeUnit) -> i64 {
+        match tu {
+            TimeUnit::Nanoseconds => 1_000_000,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1,
+        }
+    }
+
+    #[test]
+    fn test_date_scalar() -> PolarsResult<()> {
+        let s = Series::new("", [2i32]).cast(&DataType::Date)?;
+        for tu in UNITS {
+            let v = temporal_series_to_i64_scalar_in_unit(&s, tu, false)?;
+            assert_eq!(v, Some(2 * MILLISECONDS_IN_DAY * scale(tu)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_datetime_and_duration_scalar() -> PolarsResult<()> {
+        for src_tu in UNITS {
+            // 1.5 seconds in the source unit.
+            let s = Series::new("", [1_500 * scale(src_tu)]);
+            for dtype in [DataType::Datetime(src_tu, None), DataType::Duration(src_tu)] {
+                let s = s.cast(&dtype)?;
+                for tu in UNITS {
+                    let v = temporal_series_to_i64_scalar_in_unit(&s, tu, false)?;
+                    assert_eq!(v, Some(1_500 * scale(tu)));
+                }
+            }
+        }
+
+        let s = Series::new("", [None::<i64>]).cast(&DataType::Duration(TimeUnit::Milliseconds))?;
+        assert_eq!(
+            temporal_series_to_i64_scalar_in_unit(&s, TimeUnit::Nanoseconds, false)?,
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_tz_aware_datetime_scalar() -> PolarsResult<()> {
+        // 1970-01-01 12:00 UTC is 1970-01-01 13:00 in Europe/Amsterdam.
+        let hour_ms = 3_600_000;
+        let s = Series::new("", [12 * hour_ms]).cast(&DataType::Datetime(
+            TimeUnit::Milliseconds,
+            Some("Europe/Amsterdam".into()),
+        ))?;
+        for tu in UNITS {
+            let instant = temporal_series_to_i64_scalar_in_unit(&s, tu, true)?;
+            assert_eq!(instant, Some(12 * hour_ms * scale(tu)));
+            let wall_time = temporal_series_to_i64_scalar_in_unit(&s, tu, false)?;
+            assert_eq!(wall_time, Some(13 * hour_ms * scale(tu)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_wrong_length() -> PolarsResult<()> {
+        let s = Series::new("start", [1i32, 2, 3]).cast(&DataType::Date)?;
+        let err = temporal_series_to_i64_scalar(&s).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected a single value for `start`, got 3"));
+        assert!(temporal_series_to_i64_scalar(&s.slice(0, 0)).is_err());
+        assert_eq!(temporal_series_to_i64_scalar(&s.slice(2, 1))?, Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_scalar() -> PolarsResult<()> {
+        let s = Series::new("", [1i64]);
+        assert!(temporal_series_to_i64_scalar_in_unit(&s, TimeUnit::Milliseconds, false).is_err());
+        #[cfg(feature = "dtype-time")]
+        {
+            let s = s.cast(&DataType::Time)?;
+            assert!(
+                temporal_series_to_i64_scalar_in_unit(&s, TimeUnit::Nanoseconds, false).is_err()
+            );
+        }
+
+        let s = Series::new("", [i64::MAX]).cast(&DataType::Duration(TimeUnit::Milliseconds))?;
+        assert!(temporal_series_to_i64_scalar_in_unit(&s, TimeUnit::Nanoseconds, false).is_err());
+        Ok(())
+    }
+}
@@ -34,6 +34,7 @@ pub enum StringFunction {
         literal: bool,
         strict: bool,
     },
+    CommonPrefixLength,
     CountMatches(bool),
     EndsWith,
     Explode,
@@ -64,6 +65,8 @@ pub enum StringFunction {
         n: i64,
         literal: bool,
     },
+    #[cfg(feature = "string_remove_diacritics")]
+    RemoveDiacritics,
     #[cfg(feature = "string_reverse")]
     Reverse,
     #[cfg(feature = "string_pad")]
@@ -118,6 +121,7 @@ impl StringFunction {
             ConcatVertical { .. } | ConcatHorizontal(_) => mapper.with_dtype(DataType::Utf8),
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
+            CommonPrefixLength => mapper.with_dtype(DataType::UInt32),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             Explode => mapper.with_same_dtype(),
@@ -133,6 +137,8 @@ impl StringFunction {
             LenChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_remove_diacritics")]
+            RemoveDiacritics => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
@@ -182,6 +188,7 @@ impl Display for StringFunction {
         let s = match self {
             #[cfg(feature = "regex")]
             Contains { .. } => "contains",
+            CommonPrefixLength => "common_prefix_length",
             CountMatches(_) => "count_matches",
             EndsWith { .. } => "ends_with",
             Extract { .. } => "extract",
@@ -206,6 +213,8 @@ impl Display for StringFunction {
             PadStart { .. } => "pad_start",
             #[cfg(feature = "regex")]
             Replace { .. } => "replace",
+            #[cfg(feature = "string_remove_diacritics")]
+            RemoveDiacritics => "remove_diacritics",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
             #[cfg(feature = "string_encoding")]
@@ -260,6 +269,7 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         match func {
             #[cfg(feature = "regex")]
             Contains { literal, strict } => map_as_slice!(strings::contains, literal, strict),
+            CommonPrefixLength => map_as_slice!(strings::common_prefix_length),
             CountMatches(literal) => {
                 map_as_slice!(strings::count_matches, literal)
             },
@@ -309,6 +319,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConcatHorizontal(delimiter) => map_as_slice!(strings::concat_hor, &delimiter),
             #[cfg(feature = "regex")]
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
+            #[cfg(feature = "string_remove_diacritics")]
+            RemoveDiacritics => map!(strings::remove_diacritics),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
             Uppercase => map!(strings::uppercase),
@@ -384,6 +396,13 @@ pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.ends_with_chunked(suffix).into_series())
 }
 
+pub(super) fn common_prefix_length(s: &[Series]) -> PolarsResult<Series> {
+    let ca = &s[0].utf8()?.as_binary();
+    let other = &s[1].utf8()?.as_binary();
+
+    Ok(ca.common_prefix_len_chunked(other).into_series())
+}
+
 pub(super) fn starts_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = &s[0].utf8()?.as_binary();
     let prefix = &s[1].utf8()?.as_binary();
@@ -810,6 +829,12 @@ pub(super) fn replace(s: &[Series], literal: bool, n: i64) -> PolarsResult<Serie
     .map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "string_remove_diacritics")]
+pub(super) fn remove_diacritics(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.remove_diacritics().into_series())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     let ca = s.utf8()?;
@@ -0,0 +1,46 @@
+use polars_core::prelude::*;
+
+/// An argument of a function: its name and whether it accepts a data type.
+pub(super) type ArgSpec<'a> = (&'a str, fn(&DataType) -> bool);
+
+/// Check that `function` got one input per argument in `spec`, and that every input has a data
+/// type that its argument accepts.
+///
+/// Inputs of dtype `Null` are accepted by every argument.
+pub(super) fn validate_inputs(function: &str, s: &[Series], spec: &[ArgSpec]) -> PolarsResult<()> {
+    polars_ensure!(
+        s.len() == spec.len(),
+        ComputeError: "`{}` expected {} inputs, got {}", function, spec.len(), s.len()
+    );
+    for (s, (arg, accepts)) in s.iter().zip(spec) {
+        let dtype = s.dtype();
+        polars_ensure!(
+            dtype == &DataType::Null || accepts(dtype),
+            InvalidOperation: "`{}` got an invalid dtype for argument `{}`: {}", function, arg, dtype
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_inputs() {
+        let spec: &[ArgSpec] = &[
+            ("start", |dt| dt.is_integer()),
+            ("end", |dt| dt.is_integer()),
+        ];
+        let int = Series::new("", [1i64]);
+        let null = Series::full_null("", 1, &DataType::Null);
+        assert!(validate_inputs("f", &[int.clone(), int.clone()], spec).is_ok());
+        assert!(validate_inputs("f", &[int.clone(), null], spec).is_ok());
+
+        let err = validate_inputs("f", &[int.clone()], spec).unwrap_err();
+        assert!(err.to_string().contains("`f` expected 2 inputs, got 1"));
+        let float = Series::new("", [1.0f64]);
+        let err = validate_inputs("f", &[int, float], spec).unwrap_err();
+        assert!(err.to_string().contains("argument `end`: f64"));
+    }
+}
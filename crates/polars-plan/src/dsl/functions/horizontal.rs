@@ -194,33 +194,35 @@ where
 ///
 /// The name of the resulting column will be "all"; use [`alias`](Expr::alias) to choose a different name.
 pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
-    let exprs = exprs.as_ref().to_vec();
-    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    boolean_horizontal(exprs, BooleanFunction::AllHorizontal { strict: false })
+}
 
-    Ok(Expr::Function {
-        input: exprs,
-        function: FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
-        options: FunctionOptions {
-            collect_groups: ApplyOptions::ElementWise,
-            input_wildcard_expansion: true,
-            returns_scalar: false,
-            cast_to_supertypes: false,
-            allow_rename: true,
-            ..Default::default()
-        },
-    })
+/// Like [`all_horizontal`], but raises an error if any input is not boolean instead of
+/// casting it to boolean.
+pub fn all_horizontal_strict<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    boolean_horizontal(exprs, BooleanFunction::AllHorizontal { strict: true })
 }
 
 /// Create a new column with the bitwise-or of the elements in each row.
 ///
 /// The name of the resulting column will be "any"; use [`alias`](Expr::alias) to choose a different name.
 pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    boolean_horizontal(exprs, BooleanFunction::AnyHorizontal { strict: false })
+}
+
+/// Like [`any_horizontal`], but raises an error if any input is not boolean instead of
+/// casting it to boolean.
+pub fn any_horizontal_strict<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    boolean_horizontal(exprs, BooleanFunction::AnyHorizontal { strict: true })
+}
+
+fn boolean_horizontal<E: AsRef<[Expr]>>(exprs: E, function: BooleanFunction) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::Boolean(BooleanFunction::AnyHorizontal),
+        function: FunctionExpr::Boolean(function),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
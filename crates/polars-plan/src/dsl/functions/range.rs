@@ -152,6 +152,9 @@ pub fn datetime_ranges(
 }
 
 /// Generate a time range.
+///
+/// In an aggregation the range is computed per group. If either bound is null, e.g. the
+/// minimum of an empty group, the range is empty.
 #[cfg(feature = "dtype-time")]
 pub fn time_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
     let input = vec![start, end];
@@ -182,3 +185,20 @@ pub fn time_ranges(start: Expr, end: Expr, interval: Duration, closed: ClosedWin
         },
     }
 }
+
+/// Create a column of time ranges from a `List(Time)` expression whose rows are `[start, end]`
+/// pairs.
+#[cfg(feature = "dtype-time")]
+pub fn time_ranges_packed(bounds: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
+    let input = vec![bounds];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Range(RangeFunction::TimeRanges { interval, closed }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
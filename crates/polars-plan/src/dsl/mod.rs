@@ -38,7 +38,8 @@ use std::sync::Arc;
 pub use arity::*;
 #[cfg(feature = "dtype-array")]
 pub use array::*;
-use arrow::legacy::prelude::QuantileInterpolOptions;
+#[cfg(feature = "rolling_window")]
+use arrow::legacy::prelude::RollingQuantileParams;
 pub use expr::*;
 pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
@@ -47,7 +48,7 @@ pub use list::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use options::*;
-use polars_core::prelude::*;
+use polars_core::prelude::{QuantileInterpolOptions, *};
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
@@ -430,6 +431,8 @@ impl Expr {
     }
 
     /// Cast expression to another data type.
+    /// This cast is not strict: values that can't be represented in `data_type` become null.
+    /// Use [`Expr::strict_cast`] to get an error instead.
     pub fn cast(self, data_type: DataType) -> Self {
         Expr::Cast {
             expr: Box::new(self),
@@ -438,6 +441,20 @@ impl Expr {
         }
     }
 
+    /// Coerce expression to another data type, allowing the conversion to be lossy.
+    ///
+    /// This inserts the same non-strict [`Expr::Cast`] as [`Expr::cast`]: values that can't
+    /// be represented in `data_type` become null, as with the implicit coercion of the inputs
+    /// of e.g. a binary operation. The name states at the call site that this is intended.
+    /// Use [`Expr::strict_cast`] to raise an error instead.
+    pub fn type_coerce(self, data_type: DataType) -> Self {
+        Expr::Cast {
+            expr: Box::new(self),
+            data_type,
+            strict: false,
+        }
+    }
+
     /// Take the values by idx.
     pub fn gather<E: Into<Expr>>(self, idx: E) -> Self {
         Expr::Gather {
@@ -1097,6 +1114,56 @@ impl Expr {
         }
     }
 
+    /// Replace the values that are equal to an element of `old` by the corresponding element of
+    /// `new`, and all other values by `default`, or keep them if no `default` is given.
+    ///
+    /// A single `new` value is used for every element of `old`. A null in `old` matches
+    /// nulls. The mapping is built from `when/then/otherwise` expressions instead of an
+    /// opaque function, so the optimizer sees through it; e.g. if `self` is not a plain column
+    /// it is computed once by common subexpression elimination. As with any `when/then`, the
+    /// output is named after the first `new` expression.
+    ///
+    /// Returns an error if `new` has more than one element and not as many as `old`.
+    pub fn replace_expr(
+        self,
+        old: Vec<Expr>,
+        new: Vec<Expr>,
+        default: Option<Expr>,
+    ) -> PolarsResult<Expr> {
+        polars_ensure!(
+            new.len() == 1 || new.len() == old.len(),
+            InvalidOperation: "`new` should have length 1 or the same length as `old`: {}, got {}",
+            old.len(), new.len()
+        );
+        let new = match new.len() {
+            1 => vec![new[0].clone(); old.len()],
+            _ => new,
+        };
+        let default = default.unwrap_or_else(|| self.clone());
+        let branches = old
+            .into_iter()
+            .map(|old| self.clone().eq_missing(old))
+            .zip(new)
+            .collect();
+        Ok(when_then_many(branches, default))
+    }
+
+    /// Replace the values that are equal to `old` by `new`, see [`Expr::replace_expr`].
+    ///
+    /// The values are converted to literals of their exact dtype, so e.g. a datetime keeps its
+    /// time unit and time zone, and a decimal its scale. A list or struct is a single value, see
+    /// [`LiteralValue::try_from_scalar`].
+    pub fn replace_value(
+        self,
+        old: AnyValue<'_>,
+        new: AnyValue<'_>,
+        default: Option<Expr>,
+    ) -> PolarsResult<Expr> {
+        let old = Expr::Literal(LiteralValue::try_from_scalar(old)?);
+        let new = Expr::Literal(LiteralValue::try_from_scalar(new)?);
+        self.replace_expr(vec![old], vec![new], default)
+    }
+
     /// Sort this column by the ordering of another column.
     /// Can also be used in a group_by context to sort the groups.
     pub fn sort_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone, R: AsRef<[bool]>>(
@@ -1257,6 +1324,38 @@ impl Expr {
         )
     }
 
+    /// Apply a linearly interpolated rolling quantile over windows that span `window` of time,
+    /// as given by the `time_col` column, instead of a fixed number of rows.
+    ///
+    /// The window of every row covers `(t - window, t]`, where `t` is the time of that row,
+    /// so windows of irregularly sampled data hold a varying number of rows. `time_col` should
+    /// be a sorted date or datetime column without nulls.
+    ///
+    /// Returns an error if `window` is a number of rows, e.g. parsed from `"3i"`.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_quantile_by_time(
+        self,
+        time_col: &str,
+        window: Duration,
+        quantile: f64,
+    ) -> PolarsResult<Expr> {
+        polars_ensure!(
+            !window.parsed_int,
+            InvalidOperation: "`rolling_quantile_by_time` needs a temporal window, use `rolling_quantile` for a window of rows"
+        );
+        Ok(self.rolling_quantile(RollingOptions {
+            window_size: window,
+            min_periods: 1,
+            by: Some(time_col.to_string()),
+            closed_window: Some(ClosedWindow::Right),
+            fn_params: Some(Arc::new(RollingQuantileParams {
+                prob: quantile,
+                interpol: QuantileInterpolOptions::Linear,
+            }) as Arc<dyn std::any::Any + Send + Sync>),
+            ..Default::default()
+        }))
+    }
+
     /// Apply a rolling variance.
     #[cfg(feature = "rolling_window")]
     pub fn rolling_var(self, options: RollingOptions) -> Expr {
@@ -43,6 +43,17 @@ impl StringNameSpace {
         )
     }
 
+    /// The length in bytes of the longest common prefix of every string and the string of
+    /// `other` in the same row.
+    pub fn common_prefix_length(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::CommonPrefixLength),
+            &[other],
+            false,
+            true,
+        )
+    }
+
     /// Check if a string value starts with the `sub` string.
     pub fn starts_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -330,6 +341,13 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_remove_diacritics")]
+    /// Remove the diacritical marks (accents, cedillas, umlauts, ...) from each string.
+    pub fn remove_diacritics(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::RemoveDiacritics))
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
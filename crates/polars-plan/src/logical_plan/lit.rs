@@ -96,6 +96,25 @@ impl LiteralValue {
         Some(av)
     }
 
+    /// Convert a single value to a literal that broadcasts like a scalar.
+    ///
+    /// Unlike the [`TryFrom`] conversion, which turns a list into a literal of its values,
+    /// a nested value (list or struct) is carried as a single-row `Series`.
+    pub fn try_from_scalar(value: AnyValue<'_>) -> PolarsResult<Self> {
+        match value {
+            AnyValue::List(_) => Ok(Self::Series(SpecialEq::new(Series::from_any_values(
+                "literal",
+                &[value],
+                true,
+            )?))),
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(_, _, _) | AnyValue::StructOwned(_) => Ok(Self::Series(
+                SpecialEq::new(Series::from_any_values("literal", &[value], true)?),
+            )),
+            value => value.try_into(),
+        }
+    }
+
     /// Getter for the `DataType` of the value
     pub fn get_datatype(&self) -> DataType {
         match self {
@@ -182,14 +201,22 @@ impl TryFrom<AnyValue<'_>> for LiteralValue {
             AnyValue::Int64(i) => Ok(Self::Int64(i)),
             AnyValue::Float32(f) => Ok(Self::Float32(f)),
             AnyValue::Float64(f) => Ok(Self::Float64(f)),
-            #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+            // Keep the time unit and time zone, so the literal has the dtype of the value.
+            #[cfg(feature = "dtype-date")]
             AnyValue::Date(v) => Ok(LiteralValue::Date(v)),
-            #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+            #[cfg(feature = "dtype-datetime")]
             AnyValue::Datetime(value, tu, tz) => Ok(LiteralValue::DateTime(value, tu, tz.clone())),
-            #[cfg(all(feature = "temporal", feature = "dtype-duration"))]
+            #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(value, tu) => Ok(LiteralValue::Duration(value, tu)),
-            #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+            #[cfg(feature = "dtype-time")]
             AnyValue::Time(v) => Ok(LiteralValue::Time(v)),
+            // There is no decimal literal, a single-row `Series` keeps the scale.
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(_, _) => Ok(Self::Series(SpecialEq::new(Series::from_any_values(
+                "literal",
+                &[value],
+                true,
+            )?))),
             AnyValue::List(l) => Ok(Self::Series(SpecialEq::new(l))),
             AnyValue::Utf8Owned(o) => Ok(Self::Utf8(o.into())),
             #[cfg(feature = "dtype-categorical")]
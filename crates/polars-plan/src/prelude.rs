@@ -12,7 +12,7 @@ pub(crate) use polars_time::prelude::*;
 #[cfg(feature = "rolling_window")]
 pub(crate) use polars_time::{
     chunkedarray::{RollingOptions, RollingOptionsImpl},
-    Duration,
+    ClosedWindow, Duration,
 };
 pub use polars_utils::arena::{Arena, Node};
 
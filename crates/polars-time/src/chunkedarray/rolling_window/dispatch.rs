@@ -1,4 +1,7 @@
-use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
+use polars_core::{
+    with_match_physical_float_polars_type, with_match_physical_integer_polars_type,
+    with_match_physical_numeric_polars_type,
+};
 
 use super::*;
 use crate::prelude::*;
@@ -39,6 +42,7 @@ fn rolling_agg<T>(
 where
     T: PolarsNumericType,
 {
+    options.validate()?;
     if ca.is_empty() {
         return Ok(Series::new_empty(ca.name(), ca.dtype()));
     }
@@ -48,7 +52,6 @@ where
     // "5i" is a window size of 5, e.g. fixed
     let arr = if options.window_size.parsed_int {
         let options: RollingOptionsFixedWindow = options.into();
-        check_input(options.window_size, options.min_periods)?;
 
         Ok(match ca.null_count() {
             0 => rolling_agg_fn(
@@ -74,7 +77,6 @@ where
         }
         let values = arr.values().as_slice();
         let duration = options.window_size;
-        polars_ensure!(duration.duration_ns() > 0 && !duration.negative, ComputeError:"window size should be strictly positive");
         let tu = options.tu.unwrap();
         let by = options.by.unwrap();
         let closed_window = options.closed_window.expect("closed window  must be set");
@@ -174,6 +176,40 @@ pub trait SeriesOpsTime: AsSeries {
         })
     }
 
+    /// Apply a rolling quantile with a fixed window to a Series.
+    ///
+    /// Float input keeps its dtype and other numeric input gives `Float64`. Duration input
+    /// gives a duration of the same time unit, truncated to whole units.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_quantile_fixed(
+        &self,
+        options: RollingOptionsFixedWindow,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let options = RollingOptionsImpl {
+            window_size: Duration::new(options.window_size as i64),
+            min_periods: options.min_periods,
+            weights: options.weights,
+            center: options.center,
+            fn_params: Some(Arc::new(RollingQuantileParams {
+                prob: quantile,
+                interpol,
+            }) as Arc<dyn std::any::Any + Send + Sync>),
+            ..Default::default()
+        };
+        match s.dtype() {
+            DataType::Duration(tu) => s
+                .to_physical_repr()
+                .rolling_quantile(options)?
+                .cast(&DataType::Int64)?
+                .cast(&DataType::Duration(*tu)),
+            dt if dt.is_numeric() => s.rolling_quantile(options),
+            dt => polars_bail!(opq = rolling_quantile, dt),
+        }
+    }
+
     /// Apply a rolling min to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_min(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
@@ -250,3 +286,44 @@ pub trait SeriesOpsTime: AsSeries {
 }
 
 impl SeriesOpsTime for Series {}
+
+/// Apply a rolling quantile with a fixed window to a [`Series`] of which the dtype is only
+/// known at runtime.
+///
+/// A selecting interpolation (`Lower`, `Higher` or `Nearest`) on an integer [`Series`] returns
+/// values from the window, so the output keeps the integer dtype. Everything else is dispatched
+/// to [`SeriesOpsTime::rolling_quantile_fixed`].
+#[cfg(feature = "rolling_window")]
+pub fn rolling_quantile_dyn(
+    s: &Series,
+    options: RollingOptionsFixedWindow,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<Series> {
+    use QuantileInterpolOptions::*;
+
+    let selects = matches!(interpol, Lower | Higher | Nearest);
+    if !(selects && s.dtype().is_integer() && options.weights.is_none()) {
+        return s.rolling_quantile_fixed(options, quantile, interpol);
+    }
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(options.window_size as i64),
+        min_periods: options.min_periods,
+        center: options.center,
+        fn_params: Some(Arc::new(RollingQuantileParams {
+            prob: quantile,
+            interpol,
+        }) as Arc<dyn std::any::Any + Send + Sync>),
+        ..Default::default()
+    };
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        rolling_agg(
+            ca,
+            options,
+            &rolling::no_nulls::rolling_quantile_select,
+            &rolling::nulls::rolling_quantile_select,
+            None,
+        )
+    })
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+pub trait DataFrameRollingOps {
+    /// Apply a rolling quantile with a fixed window of `window_size` values to each of
+    /// `columns`.
+    ///
+    /// The result has one column per selected column, named `"{name}_rolling_quantile"`. A
+    /// window only produces a value once it is full. Non-numeric columns raise an error if
+    /// `strict` is set and are passed through unchanged otherwise.
+    fn rolling_quantile<I, S>(
+        &self,
+        window_size: usize,
+        quantile: f64,
+        columns: I,
+        strict: bool,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+}
+
+impl DataFrameRollingOps for DataFrame {
+    fn rolling_quantile<I, S>(
+        &self,
+        window_size: usize,
+        quantile: f64,
+        columns: I,
+        strict: bool,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fn_params = Some(Arc::new(RollingQuantileParams {
+            prob: quantile,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn std::any::Any + Send + Sync>);
+
+        let columns = columns
+            .into_iter()
+            .map(|name| {
+                let s = self.column(name.as_ref())?;
+                if !s.dtype().is_numeric() {
+                    polars_ensure!(!strict, opq = rolling_quantile, s.dtype());
+                    return Ok(s.clone());
+                }
+                let out = s.rolling_quantile(RollingOptionsImpl {
+                    window_size: Duration::new(window_size as i64),
+                    min_periods: window_size,
+                    fn_params: fn_params.clone(),
+                    ..Default::default()
+                })?;
+                Ok(out.with_name(&format!("{}_rolling_quantile", s.name())))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
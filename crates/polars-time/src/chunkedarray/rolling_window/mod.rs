@@ -1,4 +1,5 @@
 mod dispatch;
+mod frame;
 mod rolling_kernels;
 
 use std::convert::TryFrom;
@@ -6,6 +7,7 @@ use std::convert::TryFrom;
 use arrow::array::{Array, ArrayRef, PrimitiveArray};
 use arrow::legacy::kernels::rolling;
 pub use dispatch::*;
+pub use frame::*;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,8 +23,10 @@ pub struct RollingOptions {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    /// Not supported with a temporal window, which raises an error.
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
+    /// Not supported with a temporal window, which raises an error.
     pub center: bool,
     /// Compute the rolling aggregates with a window defined by a time column
     pub by: Option<String>,
@@ -72,8 +76,10 @@ pub struct RollingOptionsImpl<'a> {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    /// Not supported with a temporal window, which raises an error.
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
+    /// Not supported with a temporal window, which raises an error.
     pub center: bool,
     pub by: Option<&'a [i64]>,
     pub tu: Option<TimeUnit>,
@@ -156,11 +162,36 @@ impl<'a> From<RollingOptionsImpl<'a>> for RollingOptionsFixedWindow {
     }
 }
 
-/// utility
-fn check_input(window_size: usize, min_periods: usize) -> PolarsResult<()> {
-    polars_ensure!(
-        min_periods <= window_size,
-        ComputeError: "`min_periods` should be <= `window_size`",
-    );
-    Ok(())
+impl RollingOptionsImpl<'_> {
+    /// Check that these options describe a valid window, before any values are computed.
+    ///
+    /// Integer windows are checked as a [`RollingOptionsFixedWindow`]. Temporal windows need
+    /// a strictly positive duration and a `by` column, and don't support `weights` or `center`.
+    pub fn validate(&self) -> PolarsResult<()> {
+        if self.window_size.parsed_int {
+            return RollingOptionsFixedWindow::from(self.clone()).validate();
+        }
+        let duration = self.window_size;
+        polars_ensure!(
+            duration.duration_ns() > 0 && !duration.negative,
+            ComputeError: "window size should be strictly positive"
+        );
+        polars_ensure!(
+            self.by.is_some() && self.tu.is_some(),
+            ComputeError: "a temporal window size requires a `by` column"
+        );
+        polars_ensure!(
+            self.closed_window.is_some(),
+            ComputeError: "a temporal window size requires `closed` to be set"
+        );
+        polars_ensure!(
+            self.weights.is_none(),
+            ComputeError: "`weights` is not supported in 'rolling by' expression"
+        );
+        polars_ensure!(
+            !self.center,
+            ComputeError: "`center` is not supported in 'rolling by' expression"
+        );
+        Ok(())
+    }
 }
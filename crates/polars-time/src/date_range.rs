@@ -1,7 +1,10 @@
 #[cfg(feature = "timezones")]
 use arrow::legacy::kernels::Ambiguous;
 use arrow::legacy::time_zone::Tz;
-use chrono::{Datelike, NaiveDateTime, NaiveTime};
+use arrow::temporal_conversions::{
+    time64ns_to_time, MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
+};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 use polars_core::chunked_array::temporal::time_to_time64ns;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -71,6 +74,47 @@ pub fn datetime_range_impl(
     Ok(out)
 }
 
+/// Create a [`DateChunked`] from a given `start` and `end` date and a given `interval`.
+///
+/// The `interval` must consist of whole days. Month, quarter and year steps follow the
+/// calendar, so stepping a month from January 31st gives the last day of February.
+pub fn date_range_dates(
+    name: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    interval: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<DateChunked> {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let start = (start - epoch).num_days() as i32;
+    let end = (end - epoch).num_days() as i32;
+    date_range_impl(name, start, end, interval, closed)
+}
+
+#[doc(hidden)]
+pub fn date_range_impl(
+    name: &str,
+    start: i32,
+    end: i32,
+    interval: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<DateChunked> {
+    polars_ensure!(
+        interval.is_full_days(),
+        ComputeError: "`interval` of a date range must consist of whole days",
+    );
+    let start = start as i64 * MILLISECONDS_IN_DAY;
+    let end = end as i64 * MILLISECONDS_IN_DAY;
+    let values = datetime_range_i64(start, end, interval, closed, TimeUnit::Milliseconds, None)?;
+    let mut out = Int32Chunked::from_iter_values(
+        name,
+        values.into_iter().map(|v| (v / MILLISECONDS_IN_DAY) as i32),
+    )
+    .into_date();
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
 /// Create a [`TimeChunked`] from a given `start` and `end` date and a given `interval`.
 pub fn time_range(
     name: &str,
@@ -84,6 +128,38 @@ pub fn time_range(
     time_range_impl(name, start, end, interval, closed)
 }
 
+/// Create a [`TimeChunked`] with `n` points, starting from `start` and stepping by `interval`.
+///
+/// With a `closed` that excludes the start, the first point is `start + interval`. The end
+/// of the range follows from `n` and must lie before midnight.
+pub fn time_range_n(
+    name: &str,
+    start: NaiveTime,
+    interval: Duration,
+    n: usize,
+    closed: ClosedWindow,
+) -> PolarsResult<TimeChunked> {
+    let start = time_to_time64ns(&start);
+    if n == 0 {
+        return time_range_impl(name, start, start, interval, ClosedWindow::None);
+    }
+    let steps = if closed.contains_start() { n - 1 } else { n };
+    let end = i64::try_from(steps)
+        .ok()
+        .and_then(|steps| steps.checked_mul(interval.duration_ns()))
+        .and_then(|offset| start.checked_add(offset))
+        .filter(|end| *end < NANOSECONDS * SECONDS_IN_DAY);
+    let Some(end) = end else {
+        polars_bail!(ComputeError: "a time range of {} points does not fit in a day", n)
+    };
+    let closed = if closed.contains_start() {
+        ClosedWindow::Both
+    } else {
+        ClosedWindow::Right
+    };
+    time_range_impl(name, start, end, interval, closed)
+}
+
 #[doc(hidden)]
 pub fn time_range_impl(
     name: &str,
@@ -92,6 +168,7 @@ pub fn time_range_impl(
     interval: Duration,
     closed: ClosedWindow,
 ) -> PolarsResult<TimeChunked> {
+    check_time_range_direction(start, end, interval)?;
     let mut out = Int64Chunked::new_vec(
         name,
         datetime_range_i64(start, end, interval, closed, TimeUnit::Nanoseconds, None)?,
@@ -102,6 +179,91 @@ pub fn time_range_impl(
     Ok(out)
 }
 
+/// Create a [`TimeChunked`] from a given `start` and `end` time and a given `interval`,
+/// keeping only the points for which `predicate` returns `true`.
+///
+/// The predicate is called once for every candidate point, in order, with the time in
+/// nanoseconds since midnight.
+pub fn time_range_with_filter<P>(
+    name: &str,
+    start: NaiveTime,
+    end: NaiveTime,
+    interval: Duration,
+    closed: ClosedWindow,
+    predicate: P,
+) -> PolarsResult<TimeChunked>
+where
+    P: FnMut(&i64) -> bool,
+{
+    let start = time_to_time64ns(&start);
+    let end = time_to_time64ns(&end);
+    let values = time_range_iter(start, end, interval, closed)?
+        .filter(predicate)
+        .collect();
+    let mut out = Int64Chunked::new_vec(name, values).into_time();
+
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+/// Create a [`TimeChunked`] from a given `start` and `end` time and a given `interval`,
+/// with the midpoint of every two consecutive points inserted between them.
+///
+/// The output is `p0, (p0 + p1) / 2, p1, (p1 + p2) / 2, p2, ...`, with the midpoints rounded
+/// down to the nanosecond.
+pub fn time_range_with_midpoints(
+    name: &str,
+    start: NaiveTime,
+    end: NaiveTime,
+    interval: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<TimeChunked> {
+    let start = time_to_time64ns(&start);
+    let end = time_to_time64ns(&end);
+    let mut points = time_range_iter(start, end, interval, closed)?;
+    let mut values = Vec::with_capacity(points.size_hint().0 * 2);
+    if let Some(mut prev) = points.next() {
+        values.push(prev);
+        for p in points {
+            values.push(prev + (p - prev) / 2);
+            values.push(p);
+            prev = p;
+        }
+    }
+    let mut out = Int64Chunked::new_vec(name, values).into_time();
+
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+/// Iterator over the values of a time range in nanoseconds.
+///
+/// Time ranges cannot step by calendar months, so consecutive values are always
+/// a fixed number of nanoseconds apart and can be generated without allocating.
+#[doc(hidden)]
+pub fn time_range_iter(
+    start: i64,
+    end: i64,
+    interval: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<impl Iterator<Item = i64>> {
+    check_time_range_direction(start, end, interval)?;
+    check_range_bounds(start, end, interval)?;
+    polars_ensure!(
+        interval.months() == 0,
+        ComputeError: "`interval` of a time range cannot contain calendar months"
+    );
+
+    let step = interval.duration_ns();
+    let first = if closed.contains_start() {
+        Some(start)
+    } else {
+        start.checked_add(step)
+    };
+    Ok(std::iter::successors(first, move |t| t.checked_add(step))
+        .take_while(move |&t| closed.in_bounds(t, start, end)))
+}
+
 /// vector of i64 representing temporal values
 pub(crate) fn datetime_range_i64(
     start: i64,
@@ -132,32 +294,41 @@ pub(crate) fn datetime_range_i64(
     }
     let mut ts = Vec::with_capacity(size);
 
-    let mut i = match closed {
-        ClosedWindow::Both | ClosedWindow::Left => 0,
-        ClosedWindow::Right | ClosedWindow::None => 1,
+    let mut i = if closed.contains_start() { 0 } else { 1 };
+    let offset = |i: i64| -> PolarsResult<i64> {
+        let step = interval.checked_mul(i).ok_or_else(
+            || polars_err!(ComputeError: "overflow when stepping the range {} times", i),
+        )?;
+        offset_fn(&step, start, tz)
     };
-    let mut t = offset_fn(&(interval * i), start, tz)?;
+    let mut t = offset(i)?;
     i += 1;
-    match closed {
-        ClosedWindow::Both | ClosedWindow::Right => {
-            while t <= end {
-                ts.push(t);
-                t = offset_fn(&(interval * i), start, tz)?;
-                i += 1;
-            }
-        },
-        ClosedWindow::Left | ClosedWindow::None => {
-            while t < end {
-                ts.push(t);
-                t = offset_fn(&(interval * i), start, tz)?;
-                i += 1;
-            }
-        },
+    while closed.in_bounds(t, start, end) {
+        ts.push(t);
+        t = offset(i)?;
+        i += 1;
     }
     debug_assert!(size >= ts.len());
     Ok(ts)
 }
 
+/// Raise a clear error if the `interval` steps away from `end`, instead of the generic
+/// bounds errors below. `start` and `end` are in nanoseconds since midnight.
+fn check_time_range_direction(start: i64, end: i64, interval: Duration) -> PolarsResult<()> {
+    let mismatch = if interval.negative {
+        end > start
+    } else {
+        end < start && !interval.is_zero()
+    };
+    polars_ensure!(
+        !mismatch,
+        ComputeError: "interval sign does not match range direction (start={}, end={}, interval={}{}ns)",
+        time64ns_to_time(start), time64ns_to_time(end),
+        if interval.negative { "-" } else { "" }, interval.duration_ns()
+    );
+    Ok(())
+}
+
 fn check_range_bounds(start: i64, end: i64, interval: Duration) -> PolarsResult<()> {
     polars_ensure!(end >= start, ComputeError: "`end` must be equal to or greater than `start`");
     polars_ensure!(!interval.negative && !interval.is_zero(), ComputeError: "`interval` must be positive");
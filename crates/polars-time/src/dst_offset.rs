@@ -25,3 +25,95 @@ pub fn dst_offset(ca: &DatetimeChunked, time_unit: &TimeUnit, time_zone: &Tz) ->
     })
     .into_duration(TimeUnit::Milliseconds)
 }
+
+/// The DST offset in minutes that `time_zone` applies at every UTC timestamp of `ca`: 0 in
+/// standard time and usually 60 in daylight saving time.
+#[cfg(feature = "timezones")]
+pub fn dst_offset_minutes(
+    ca: &DatetimeChunked,
+    time_unit: &TimeUnit,
+    time_zone: &Tz,
+) -> Int32Chunked {
+    let timestamp_to_datetime = match time_unit {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    ca.0.apply_values_generic(|t| {
+        let ndt = timestamp_to_datetime(t);
+        let dt = time_zone.from_utc_datetime(&ndt);
+        dt.offset().dst_offset().num_minutes() as i32
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "timezones")]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_dst_offset_transitions() {
+        let ts = |month, day, hour, minute| {
+            NaiveDate::from_ymd_opt(2023, month, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+        // London switches to summer time at 01:00 UTC on 2023-03-26 and back at 01:00 UTC
+        // on 2023-10-29.
+        let ca = Int64Chunked::new(
+            "",
+            &[
+                ts(3, 26, 0, 59),
+                ts(3, 26, 1, 0),
+                ts(7, 1, 12, 0),
+                ts(10, 29, 0, 59),
+                ts(10, 29, 1, 0),
+            ],
+        )
+        .into_datetime(TimeUnit::Milliseconds, Some("Europe/London".to_string()));
+        let tz: Tz = "Europe/London".parse().unwrap();
+
+        let out = dst_offset(&ca, &TimeUnit::Milliseconds, &tz);
+        let hour = 3_600_000;
+        assert_eq!(
+            Vec::from(&out.0),
+            &[Some(0), Some(hour), Some(hour), Some(hour), Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_dst_offset_minutes() {
+        let ts = |month, day, hour, minute| {
+            NaiveDate::from_ymd_opt(2023, month, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+                .timestamp_micros()
+        };
+        // New York switches to summer time at 07:00 UTC on 2023-03-12 and back at 06:00 UTC
+        // on 2023-11-05. The timestamps are UTC, so the column itself needs no time zone.
+        let ca = Int64Chunked::new(
+            "",
+            &[
+                Some(ts(3, 12, 6, 59)),
+                Some(ts(3, 12, 7, 0)),
+                None,
+                Some(ts(7, 1, 12, 0)),
+                Some(ts(11, 5, 5, 59)),
+                Some(ts(11, 5, 6, 0)),
+            ],
+        )
+        .into_datetime(TimeUnit::Microseconds, None);
+        let tz: Tz = "America/New_York".parse().unwrap();
+
+        let out = dst_offset_minutes(&ca, &TimeUnit::Microseconds, &tz);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(60), None, Some(60), Some(60), Some(0)]
+        );
+    }
+}
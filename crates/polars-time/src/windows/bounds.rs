@@ -31,39 +31,29 @@ impl Bounds {
     // check if unit is within bounds
     #[inline]
     pub(crate) fn is_member(&self, t: i64, closed: ClosedWindow) -> bool {
-        match closed {
-            ClosedWindow::Right => t > self.start && t <= self.stop,
-            ClosedWindow::Left => t >= self.start && t < self.stop,
-            ClosedWindow::None => t > self.start && t < self.stop,
-            ClosedWindow::Both => t >= self.start && t <= self.stop,
-        }
+        closed.in_bounds(t, self.start, self.stop)
     }
 
     #[inline]
     pub(crate) fn is_member_entry(&self, t: i64, closed: ClosedWindow) -> bool {
-        match closed {
-            ClosedWindow::Right => t > self.start,
-            ClosedWindow::Left => t >= self.start,
-            ClosedWindow::None => t > self.start,
-            ClosedWindow::Both => t >= self.start,
+        if closed.contains_start() {
+            t >= self.start
+        } else {
+            t > self.start
         }
     }
 
     #[inline]
     pub(crate) fn is_member_exit(&self, t: i64, closed: ClosedWindow) -> bool {
-        match closed {
-            ClosedWindow::Right => t <= self.stop,
-            ClosedWindow::Left => t < self.stop,
-            ClosedWindow::None => t < self.stop,
-            ClosedWindow::Both => t <= self.stop,
+        if closed.contains_end() {
+            t <= self.stop
+        } else {
+            t < self.stop
         }
     }
 
     #[inline]
     pub(crate) fn is_future(&self, t: i64, closed: ClosedWindow) -> bool {
-        match closed {
-            ClosedWindow::Left | ClosedWindow::None => self.stop <= t,
-            ClosedWindow::Both | ClosedWindow::Right => t > self.stop,
-        }
+        !self.is_member_exit(t, closed)
     }
 }
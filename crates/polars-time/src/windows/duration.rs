@@ -5,18 +5,20 @@ use std::ops::Mul;
 use arrow::legacy::kernels::Ambiguous;
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::{
-    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
+    timestamp_ms_to_datetime, timestamp_ms_to_datetime_opt, timestamp_ns_to_datetime,
+    timestamp_ns_to_datetime_opt, timestamp_us_to_datetime, timestamp_us_to_datetime_opt,
+    MILLISECONDS,
 };
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    PolarsResult, TimeUnit,
 };
 use polars_core::utils::arrow::temporal_conversions::NANOSECONDS;
 use polars_error::*;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::calendar::{
     NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
@@ -25,8 +27,12 @@ use super::calendar::{
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{is_leap_year, last_day_of_month};
 
+/// A calendar-aware duration.
+///
+/// With `serde` it is serialized as its component fields. It can also be deserialized from
+/// the string form accepted by [`Duration::parse`] in human-readable formats, such as JSON.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Duration {
     // the number of months for the duration
     months: i64,
@@ -42,6 +48,65 @@ pub struct Duration {
     pub parsed_int: bool,
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        #[serde(rename = "Duration")]
+        struct Fields {
+            months: i64,
+            weeks: i64,
+            days: i64,
+            nsecs: i64,
+            negative: bool,
+            parsed_int: bool,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Fields(Fields),
+            Str(String),
+        }
+
+        let fields = if deserializer.is_human_readable() {
+            match Repr::deserialize(deserializer)? {
+                Repr::Fields(fields) => fields,
+                Repr::Str(s) => return Duration::try_parse(&s).map_err(D::Error::custom),
+            }
+        } else {
+            Fields::deserialize(deserializer)?
+        };
+        let Fields {
+            months,
+            weeks,
+            days,
+            nsecs,
+            negative,
+            parsed_int,
+        } = fields;
+        // The sign is only stored in `negative`.
+        if months < 0 || weeks < 0 || days < 0 || nsecs < 0 {
+            return Err(D::Error::custom(
+                "the components of a Duration must not be negative",
+            ));
+        }
+        Ok(Duration {
+            months,
+            weeks,
+            days,
+            nsecs,
+            negative,
+            parsed_int,
+        })
+    }
+}
+
 impl PartialOrd<Self> for Duration {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -102,13 +167,22 @@ impl Duration {
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
+        Self::try_parse(duration).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parse a string into a `Duration`, see [`Duration::parse`] for the format.
+    ///
+    /// Returns an error instead of panicking if the given str is invalid.
+    pub fn try_parse(duration: &str) -> PolarsResult<Self> {
         let num_minus_signs = duration.matches('-').count();
-        if num_minus_signs > 1 {
-            panic!("a Duration string can only have a single minus sign")
-        }
-        if (num_minus_signs > 0) & !duration.starts_with('-') {
-            panic!("only a single minus sign is allowed, at the front of the string")
-        }
+        polars_ensure!(
+            num_minus_signs <= 1,
+            ComputeError: "a Duration string can only have a single minus sign"
+        );
+        polars_ensure!(
+            num_minus_signs == 0 || duration.starts_with('-'),
+            ComputeError: "only a single minus sign is allowed, at the front of the string"
+        );
 
         let mut nsecs = 0;
         let mut weeks = 0;
@@ -129,9 +203,9 @@ impl Duration {
         let mut unit = String::with_capacity(2);
         while let Some((i, mut ch)) = iter.next() {
             if !ch.is_ascii_digit() {
-                let n = duration[start..i]
-                    .parse::<i64>()
-                    .expect("expected an integer in the duration string");
+                let n = duration[start..i].parse::<i64>().map_err(
+                    |_| polars_err!(ComputeError: "expected an integer in the duration string"),
+                )?;
 
                 loop {
                     if ch.is_ascii_alphabetic() {
@@ -149,9 +223,10 @@ impl Duration {
                         },
                     }
                 }
-                if unit.is_empty() {
-                    panic!("expected a unit in the duration string")
-                }
+                polars_ensure!(
+                    !unit.is_empty(),
+                    ComputeError: "expected a unit in the duration string"
+                );
 
                 match &*unit {
                     "ns" => nsecs += n,
@@ -162,29 +237,29 @@ impl Duration {
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
                     "w" => weeks += n,
-                    "mo" => {
-                        months += n
-                    }
+                    "mo" => months += n,
                     "q" => months += n * 3,
                     "y" => months += n * 12,
                     // we will read indexes as nanoseconds
                     "i" => {
                         nsecs += n;
                         parsed_int = true;
-                    }
-                    unit => panic!("unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'"),
+                    },
+                    unit => {
+                        polars_bail!(ComputeError: "unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'")
+                    },
                 }
                 unit.clear();
             }
         }
-        Duration {
+        Ok(Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
             weeks: weeks.abs(),
             months: months.abs(),
             negative,
             parsed_int,
-        }
+        })
     }
 
     fn to_positive(v: i64) -> (bool, i64) {
@@ -361,8 +436,33 @@ impl Duration {
                 + self.days * NS_DAY / 1_000_000)
     }
 
+    /// Multiply the `Duration` by `rhs`, returning `None` if any of its components overflows.
+    pub fn checked_mul(&self, rhs: i64) -> Option<Self> {
+        let mut out = *self;
+        if rhs < 0 {
+            out.negative = !out.negative;
+        }
+        let rhs = rhs.checked_abs()?;
+        out.months = out.months.checked_mul(rhs)?;
+        out.weeks = out.weeks.checked_mul(rhs)?;
+        out.days = out.days.checked_mul(rhs)?;
+        out.nsecs = out.nsecs.checked_mul(rhs)?;
+        Some(out)
+    }
+
+    /// Add the `Duration` to the timestamp `t` of unit `tu`, accounting for calendar months,
+    /// weeks and days. Returns `None` if the result is not a representable timestamp.
+    pub fn checked_add_to(&self, t: i64, tu: TimeUnit) -> Option<i64> {
+        match tu {
+            TimeUnit::Nanoseconds => self.add_ns(t, None),
+            TimeUnit::Microseconds => self.add_us(t, None),
+            TimeUnit::Milliseconds => self.add_ms(t, None),
+        }
+        .ok()
+    }
+
     #[doc(hidden)]
-    fn add_month(ts: NaiveDateTime, n_months: i64, negative: bool) -> NaiveDateTime {
+    fn add_month(ts: NaiveDateTime, n_months: i64, negative: bool) -> Option<NaiveDateTime> {
         let mut months = n_months;
         if negative {
             months = -months;
@@ -373,7 +473,7 @@ impl Duration {
         let mut year = ts.year();
         let mut month = ts.month() as i32;
         let mut day = ts.day();
-        year += (months / 12) as i32;
+        year = year.checked_add(i32::try_from(months / 12).ok()?)?;
         month += (months % 12) as i32;
 
         // if the month overflowed or underflowed, adjust the year
@@ -403,9 +503,7 @@ impl Duration {
         let minute = ts.minute();
         let sec = ts.second();
         let nsec = ts.nanosecond();
-        new_datetime(year, month as u32, day, hour, minute, sec, nsec).expect(
-            "Expected valid datetime, please open an issue at https://github.com/pola-rs/polars/issues"
-        )
+        new_datetime(year, month as u32, day, hour, minute, sec, nsec)
     }
 
     /// Localize result to given time zone., respecting DST fold of original datetime.
@@ -670,102 +768,102 @@ impl Duration {
     ) -> PolarsResult<i64>
     where
         F: Fn(i64) -> i64,
-        G: Fn(i64) -> NaiveDateTime,
-        J: Fn(NaiveDateTime) -> i64,
+        G: Fn(i64) -> Option<NaiveDateTime>,
+        J: Fn(NaiveDateTime) -> Option<i64>,
     {
         let d = self;
         let mut new_t = t;
+        let overflow =
+            || polars_err!(ComputeError: "overflow when adding a duration to timestamp {}", t);
+        let to_datetime = |t| timestamp_to_datetime(t).ok_or_else(overflow);
+        let to_timestamp = |dt| datetime_to_timestamp(dt).ok_or_else(overflow);
 
         if d.months > 0 {
             let ts = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
-                _ => timestamp_to_datetime(t),
+                Some(tz) => unlocalize_datetime(to_datetime(t)?, tz),
+                _ => to_datetime(t)?,
             };
-            let dt = Self::add_month(ts, d.months, d.negative);
+            let dt = Self::add_month(ts, d.months, d.negative).ok_or_else(overflow)?;
             new_t = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => datetime_to_timestamp(try_localize_datetime(dt, tz, Ambiguous::Raise)?),
-                _ => datetime_to_timestamp(dt),
+                Some(tz) => to_timestamp(try_localize_datetime(dt, tz, Ambiguous::Raise)?)?,
+                _ => to_timestamp(dt)?,
             };
         }
 
         if d.weeks > 0 {
-            let t_weeks = nsecs_to_unit(NS_WEEK) * self.weeks;
+            let t_weeks = nsecs_to_unit(NS_WEEK)
+                .checked_mul(self.weeks)
+                .ok_or_else(overflow)?;
+            let t_weeks = if d.negative { -t_weeks } else { t_weeks };
             match tz {
                 #[cfg(feature = "timezones")]
                 Some(tz) => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
-                    new_t += if d.negative { -t_weeks } else { t_weeks };
-                    new_t = datetime_to_timestamp(try_localize_datetime(
-                        timestamp_to_datetime(new_t),
+                    new_t = to_timestamp(unlocalize_datetime(to_datetime(t)?, tz))?;
+                    new_t = new_t.checked_add(t_weeks).ok_or_else(overflow)?;
+                    new_t = to_timestamp(try_localize_datetime(
+                        to_datetime(new_t)?,
                         tz,
                         Ambiguous::Raise,
-                    )?);
+                    )?)?;
                 },
-                _ => new_t += if d.negative { -t_weeks } else { t_weeks },
+                _ => new_t = new_t.checked_add(t_weeks).ok_or_else(overflow)?,
             };
         }
 
         if d.days > 0 {
-            let t_days = nsecs_to_unit(NS_DAY) * self.days;
+            let t_days = nsecs_to_unit(NS_DAY)
+                .checked_mul(self.days)
+                .ok_or_else(overflow)?;
+            let t_days = if d.negative { -t_days } else { t_days };
             match tz {
                 #[cfg(feature = "timezones")]
                 Some(tz) => {
-                    new_t =
-                        datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
-                    new_t += if d.negative { -t_days } else { t_days };
-                    new_t = datetime_to_timestamp(try_localize_datetime(
-                        timestamp_to_datetime(new_t),
+                    new_t = to_timestamp(unlocalize_datetime(to_datetime(t)?, tz))?;
+                    new_t = new_t.checked_add(t_days).ok_or_else(overflow)?;
+                    new_t = to_timestamp(try_localize_datetime(
+                        to_datetime(new_t)?,
                         tz,
                         Ambiguous::Raise,
-                    )?);
+                    )?)?;
                 },
-                _ => new_t += if d.negative { -t_days } else { t_days },
+                _ => new_t = new_t.checked_add(t_days).ok_or_else(overflow)?,
             };
         }
 
-        Ok(new_t)
+        let nsecs = nsecs_to_unit(if d.negative { -d.nsecs } else { d.nsecs });
+        new_t.checked_add(nsecs).ok_or_else(overflow)
     }
 
     pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
-        let new_t = self.add_impl_month_week_or_day(
+        self.add_impl_month_week_or_day(
             t,
             tz,
             |nsecs| nsecs,
-            timestamp_ns_to_datetime,
-            datetime_to_timestamp_ns,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs)
+            timestamp_ns_to_datetime_opt,
+            |dt| dt.timestamp_nanos_opt(),
+        )
     }
 
     pub fn add_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
-        let new_t = self.add_impl_month_week_or_day(
+        self.add_impl_month_week_or_day(
             t,
             tz,
             |nsecs| nsecs / 1000,
-            timestamp_us_to_datetime,
-            datetime_to_timestamp_us,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000)
+            timestamp_us_to_datetime_opt,
+            |dt| Some(dt.timestamp_micros()),
+        )
     }
 
     pub fn add_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
-        let new_t = self.add_impl_month_week_or_day(
+        self.add_impl_month_week_or_day(
             t,
             tz,
             |nsecs| nsecs / 1_000_000,
-            timestamp_ms_to_datetime,
-            datetime_to_timestamp_ms,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000_000)
+            timestamp_ms_to_datetime_opt,
+            |dt| Some(dt.timestamp_millis()),
+        )
     }
 }
 
@@ -843,4 +941,89 @@ mod test {
             one_week_negative.add_ns(t, None).unwrap()
         );
     }
+
+    #[test]
+    fn test_checked_mul() {
+        let out = Duration::parse("1mo1d").checked_mul(-3).unwrap();
+        assert_eq!(out.months(), 3);
+        assert_eq!(out.days(), 3);
+        assert!(out.negative);
+
+        assert!(Duration::parse("1mo").checked_mul(i64::MAX).is_some());
+        assert!(Duration::parse("2mo").checked_mul(i64::MAX).is_none());
+        assert!(Duration::parse("1ns").checked_mul(i64::MIN).is_none());
+    }
+
+    #[test]
+    fn test_checked_add_to() {
+        let one_month = Duration::parse("1mo");
+        assert_eq!(
+            one_month.checked_add_to(0, TimeUnit::Milliseconds),
+            Some(31 * NS_DAY / 1_000_000)
+        );
+        // 2262-04-11 plus a month is beyond the range of nanosecond timestamps.
+        assert_eq!(
+            one_month.checked_add_to(i64::MAX, TimeUnit::Nanoseconds),
+            None
+        );
+
+        let one_ns = Duration::parse("1ns");
+        assert_eq!(
+            one_ns.checked_add_to(i64::MAX - 1, TimeUnit::Nanoseconds),
+            Some(i64::MAX)
+        );
+        assert_eq!(one_ns.checked_add_to(i64::MAX, TimeUnit::Nanoseconds), None);
+        assert_eq!(
+            Duration::parse("-1ns").checked_add_to(i64::MIN, TimeUnit::Nanoseconds),
+            None
+        );
+        assert!(Duration::parse("1d").add_ns(i64::MAX, None).is_err());
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert_eq!(
+            Duration::try_parse("-1w2d").unwrap(),
+            Duration::parse("-1w2d")
+        );
+        assert!(Duration::try_parse("1d-2h").is_err());
+        assert!(Duration::try_parse("--1d").is_err());
+        assert!(Duration::try_parse("1x").is_err());
+        assert!(Duration::try_parse("d").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_roundtrip() {
+        use crate::windows::group_by::ClosedWindow;
+
+        for s in ["1ns", "-1w2d", "1mo3d", "-2y1q4h30m", "-3i"] {
+            let duration = Duration::parse(s);
+            let json = serde_json::to_value(duration).unwrap();
+            assert_eq!(json["negative"], duration.negative);
+            let out: Duration = serde_json::from_value(json).unwrap();
+            assert_eq!(out, duration);
+            assert_eq!(out.months(), duration.months());
+        }
+
+        // The string form is still accepted.
+        let out: Duration = serde_json::from_str(r#""-1mo2d""#).unwrap();
+        assert_eq!(out, Duration::parse("-1mo2d"));
+        assert!(serde_json::from_str::<Duration>(r#""1x""#).is_err());
+
+        let json =
+            r#"{"months":-1,"weeks":0,"days":0,"nsecs":0,"negative":false,"parsed_int":false}"#;
+        assert!(serde_json::from_str::<Duration>(json).is_err());
+
+        for (s, closed) in [
+            (r#""Both""#, ClosedWindow::Both),
+            (r#""left""#, ClosedWindow::Left),
+            (r#""none""#, ClosedWindow::None),
+        ] {
+            let out: ClosedWindow = serde_json::from_str(s).unwrap();
+            assert_eq!(out, closed);
+            let json = serde_json::to_string(&closed).unwrap();
+            assert_eq!(serde_json::from_str::<ClosedWindow>(&json).unwrap(), closed);
+        }
+    }
 }
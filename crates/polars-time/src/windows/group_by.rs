@@ -1,3 +1,6 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
 use arrow::legacy::time_zone::Tz;
 use arrow::legacy::trusted_len::TrustedLen;
 use polars_core::export::rayon::prelude::*;
@@ -14,12 +17,76 @@ use crate::prelude::*;
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClosedWindow {
+    // The lowercase aliases are the names used by the Python API.
+    #[cfg_attr(feature = "serde", serde(alias = "left"))]
     Left,
+    #[cfg_attr(feature = "serde", serde(alias = "right"))]
     Right,
+    #[cfg_attr(feature = "serde", serde(alias = "both"))]
     Both,
+    #[cfg_attr(feature = "serde", serde(alias = "none"))]
     None,
 }
 
+impl ClosedWindow {
+    /// Whether the start of the window is part of the window.
+    #[inline]
+    pub fn contains_start(&self) -> bool {
+        matches!(self, ClosedWindow::Left | ClosedWindow::Both)
+    }
+
+    /// Whether the end of the window is part of the window.
+    #[inline]
+    pub fn contains_end(&self) -> bool {
+        matches!(self, ClosedWindow::Right | ClosedWindow::Both)
+    }
+
+    /// Check if `t` lies within the window running from `start` to `end`.
+    #[inline]
+    pub fn in_bounds(&self, t: i64, start: i64, end: i64) -> bool {
+        let after_start = if self.contains_start() {
+            t >= start
+        } else {
+            t > start
+        };
+        let before_end = if self.contains_end() {
+            t <= end
+        } else {
+            t < end
+        };
+        after_start && before_end
+    }
+}
+
+impl FromStr for ClosedWindow {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let closed = match s {
+            "left" => ClosedWindow::Left,
+            "right" => ClosedWindow::Right,
+            "both" => ClosedWindow::Both,
+            "none" => ClosedWindow::None,
+            v => polars_bail!(
+                InvalidOperation: "`closed` must be one of {{'left', 'right', 'both', 'none'}}, got {}", v
+            ),
+        };
+        Ok(closed)
+    }
+}
+
+impl Display for ClosedWindow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ClosedWindow::Left => "left",
+            ClosedWindow::Right => "right",
+            ClosedWindow::Both => "both",
+            ClosedWindow::None => "none",
+        };
+        f.write_str(s)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Label {
@@ -520,10 +587,8 @@ pub fn group_by_values(
                     .collect::<PolarsResult<Vec<_>>>()?;
                 Ok(flatten_par(&vals))
             })
-        } else if ((offset.duration_ns() >= period.duration_ns())
-            && matches!(closed_window, ClosedWindow::Left | ClosedWindow::None))
-            || ((offset.duration_ns() > period.duration_ns())
-                && matches!(closed_window, ClosedWindow::Right | ClosedWindow::Both))
+        } else if ((offset.duration_ns() >= period.duration_ns()) && !closed_window.contains_end())
+            || ((offset.duration_ns() > period.duration_ns()) && closed_window.contains_end())
         {
             // window is completely behind t and t itself is not a member
             // ---------------t---
@@ -901,3 +901,43 @@ fn test_group_by_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_closed_window_bounds() {
+    use ClosedWindow::*;
+
+    for (closed, contains_start, contains_end) in [
+        (Left, true, false),
+        (Right, false, true),
+        (Both, true, true),
+        (None, false, false),
+    ] {
+        assert_eq!(closed.contains_start(), contains_start);
+        assert_eq!(closed.contains_end(), contains_end);
+
+        assert!(!closed.in_bounds(-1, 0, 10));
+        assert_eq!(closed.in_bounds(0, 0, 10), contains_start);
+        assert!(closed.in_bounds(5, 0, 10));
+        assert_eq!(closed.in_bounds(10, 0, 10), contains_end);
+        assert!(!closed.in_bounds(11, 0, 10));
+        // A zero-width window only contains its point if it is closed on both sides.
+        assert_eq!(closed.in_bounds(0, 0, 0), contains_start && contains_end);
+    }
+}
+
+#[test]
+fn test_closed_window_from_str() {
+    for closed in [
+        ClosedWindow::Left,
+        ClosedWindow::Right,
+        ClosedWindow::Both,
+        ClosedWindow::None,
+    ] {
+        let parsed: ClosedWindow = closed.to_string().parse().unwrap();
+        assert_eq!(parsed, closed);
+    }
+    assert_eq!(ClosedWindow::Both.to_string(), "both");
+
+    let err = "Left".parse::<ClosedWindow>().unwrap_err();
+    assert!(err.to_string().contains("'left', 'right', 'both', 'none'"));
+}
@@ -0,0 +1,175 @@
+//! A Count-Min Sketch estimates how often items occur in a stream in a fixed amount of memory,
+//! independent of the number of distinct items.
+//!
+//! The sketch holds `depth` rows of `width` counters, every item increments one counter per
+//! row. The estimated count of an item is the minimum of its counters, which never
+//! underestimates the true count. With `width = ⌈e / ε⌉` and `depth = ⌈ln(1 / δ)⌉`, the estimate
+//! exceeds the true count by at most `ε · n` with probability at least `1 - δ`, where `n` is
+//! the total count in the sketch. See [`CountMinSketch::with_error`].
+//!
+//! ```
+//! # use polars_utils::count_min::CountMinSketch;
+//! let mut cms = CountMinSketch::new(64, 4);
+//! cms.insert(&"a");
+//! cms.insert(&"a");
+//! cms.insert(&"b");
+//! assert!(cms.estimate(&"a") >= 2);
+//!
+//! cms.remove(&"a");
+//! assert_eq!(cms.total(), 2);
+//! ```
+use std::hash::Hash;
+
+use ahash::RandomState;
+
+/// Fixed seed, so the estimates are the same across runs.
+const SEED: RandomState = RandomState::with_seeds(
+    0x2d358dccaa6c78a5_u64,
+    0x8bb84b93962eacc9_u64,
+    0x4b33a62ed433d4a3_u64,
+    0x4d5a2da51de1aa47_u64,
+);
+
+#[derive(Clone, Debug)]
+pub struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    /// `depth` rows of `width` counters, stored row after row.
+    counters: Vec<u64>,
+    total: u64,
+}
+
+impl CountMinSketch {
+    /// Create an empty sketch with `depth` rows of `width` counters.
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` is zero.
+    pub fn new(width: usize, depth: usize) -> Self {
+        assert!(
+            width > 0 && depth > 0,
+            "the width and depth of a Count-Min Sketch must be positive"
+        );
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth],
+            total: 0,
+        }
+    }
+
+    /// Create an empty sketch whose estimates exceed the true counts by at most `epsilon`
+    /// times the total count, with probability at least `1 - delta`.
+    ///
+    /// # Panics
+    /// Panics if `epsilon` or `delta` is not in `(0, 1)`.
+    pub fn with_error(epsilon: f64, delta: f64) -> Self {
+        assert!(
+            epsilon > 0.0 && epsilon < 1.0 && delta > 0.0 && delta < 1.0,
+            "the error bounds of a Count-Min Sketch must lie in (0, 1)"
+        );
+        let width = (std::f64::consts::E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self::new(width, depth)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of items in the sketch, the inserted items minus the removed ones.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The index of the counter of `item` in every row.
+    ///
+    /// The rows use the double hashing `h1 + i * h2` of a single hash, which keeps the
+    /// guarantees of independent hash functions.
+    #[inline]
+    fn indices<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let hash = SEED.hash_one(item);
+        let h1 = hash & 0xffff_ffff;
+        // Odd, so it never maps every row to the same counter.
+        let h2 = (hash >> 32) | 1;
+        let width = self.width as u64;
+        (0..self.depth as u64)
+            .map(move |i| (i * width + h1.wrapping_add(i.wrapping_mul(h2)) % width) as usize)
+    }
+
+    /// Count one occurrence of `item`.
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for idx in self.indices(item) {
+            self.counters[idx] += 1;
+        }
+        self.total += 1;
+    }
+
+    /// Remove one occurrence of `item`, which must have been inserted before.
+    ///
+    /// # Panics
+    /// May panic if `item` was not inserted, as a counter would underflow.
+    pub fn remove<T: Hash + ?Sized>(&mut self, item: &T) {
+        for idx in self.indices(item) {
+            self.counters[idx] -= 1;
+        }
+        self.total -= 1;
+    }
+
+    /// The estimated count of `item`. This is never less than its true count.
+    pub fn estimate<T: Hash + ?Sized>(&self, item: &T) -> u64 {
+        self.indices(item)
+            .map(|idx| self.counters[idx])
+            .min()
+            .unwrap()
+    }
+
+    /// Remove all items.
+    pub fn clear(&mut self) {
+        self.counters.fill(0);
+        self.total = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_min_sketch() {
+        let mut cms = CountMinSketch::with_error(0.01, 0.01);
+        assert_eq!(cms.width(), 272);
+        assert_eq!(cms.depth(), 5);
+
+        for i in 0..1000u32 {
+            for _ in 0..i % 4 {
+                cms.insert(&i);
+            }
+        }
+        let bound = (0.01 * cms.total() as f64) as u64;
+        let mut exact = 0;
+        for i in 0..1000u32 {
+            let est = cms.estimate(&i);
+            let count = (i % 4) as u64;
+            assert!(est >= count);
+            exact += (est - count <= bound) as usize;
+        }
+        // At most 1% of the estimates may exceed the bound.
+        assert!(exact >= 990);
+
+        for i in 0..1000u32 {
+            for _ in 0..i % 4 {
+                cms.remove(&i);
+            }
+        }
+        assert_eq!(cms.total(), 0);
+        assert_eq!(cms.estimate(&3u32), 0);
+
+        cms.insert("a");
+        cms.clear();
+        assert_eq!(cms.estimate("a"), 0);
+    }
+}
@@ -93,3 +93,71 @@ macro_rules! impl_is_float {
 
 impl_is_float!(f32, true, false);
 impl_is_float!(f64, false, true);
+
+/// The minimum of `a` and `b` that ignores NaN: the result is only NaN if both are NaN.
+#[inline]
+pub fn nan_min<T: PartialOrd + IsFloat>(a: T, b: T) -> T {
+    if a.is_nan() || b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The maximum of `a` and `b` that ignores NaN: the result is only NaN if both are NaN.
+#[inline]
+pub fn nan_max<T: PartialOrd + IsFloat>(a: T, b: T) -> T {
+    if a.is_nan() || b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The minimum of `a` and `b` that propagates NaN: the result is NaN if either is NaN.
+#[inline]
+pub fn propagate_nan_min<T: PartialOrd + IsFloat>(a: T, b: T) -> T {
+    if a.is_nan() || !(b.is_nan() || b < a) {
+        a
+    } else {
+        b
+    }
+}
+
+/// The maximum of `a` and `b` that propagates NaN: the result is NaN if either is NaN.
+#[inline]
+pub fn propagate_nan_max<T: PartialOrd + IsFloat>(a: T, b: T) -> T {
+    if a.is_nan() || !(b.is_nan() || b > a) {
+        a
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nan_min_max() {
+        let nan = f64::NAN;
+        for (a, b) in [(1.0, 2.0), (2.0, 1.0), (1.0, 1.0)] {
+            assert_eq!(nan_min(a, b), a.min(b));
+            assert_eq!(nan_max(a, b), a.max(b));
+            assert_eq!(propagate_nan_min(a, b), a.min(b));
+            assert_eq!(propagate_nan_max(a, b), a.max(b));
+        }
+        for (a, b) in [(nan, 1.0), (1.0, nan)] {
+            assert_eq!(nan_min(a, b), 1.0);
+            assert_eq!(nan_max(a, b), 1.0);
+            assert!(propagate_nan_min(a, b).is_nan());
+            assert!(propagate_nan_max(a, b).is_nan());
+        }
+        assert!(nan_min(nan, nan).is_nan());
+        assert!(nan_max(nan, nan).is_nan());
+
+        // Integers are never NaN.
+        assert_eq!(nan_min(3, -1), -1);
+        assert_eq!(propagate_nan_max(3u8, 7), 7);
+    }
+}
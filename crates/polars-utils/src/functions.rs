@@ -1,5 +1,9 @@
+use std::fmt::Display;
 use std::ops::Range;
 
+use num_traits::{Float, NumCast};
+use polars_error::{polars_err, PolarsResult};
+
 // The ith portion of a range split in k (as equal as possible) parts.
 #[inline(always)]
 pub fn range_portion(i: usize, k: usize, r: Range<usize>) -> Range<usize> {
@@ -23,3 +27,209 @@ pub fn flatten<T: Clone, R: AsRef<[T]>>(bufs: &[R], len: Option<usize>) -> Vec<T
     }
     out
 }
+
+/// Interpolate linearly from `lo` to `hi`, as `lo + (hi - lo) * frac`, where `frac` is
+/// clamped to `[0, 1]`.
+///
+/// A `frac` of 0 or 1 gives `lo` or `hi` exactly, and so does the interpolation between two
+/// equal values. See [`Lerp`] for how floats and integers are interpolated.
+pub fn lerp<T: Lerp>(lo: T, hi: T, frac: f64) -> T {
+    T::lerp(lo, hi, frac.clamp(0.0, 1.0))
+}
+
+/// [`lerp`] for any float type, e.g. the values of a kernel that is generic over floats.
+pub fn lerp_float<T: Float>(lo: T, hi: T, frac: f64) -> T {
+    let frac = frac.clamp(0.0, 1.0);
+    if frac == 0.0 || lo == hi {
+        lo
+    } else if frac == 1.0 {
+        hi
+    } else {
+        lo + (hi - lo) * T::from(frac).unwrap()
+    }
+}
+
+/// Types that [`lerp`] can interpolate.
+///
+/// Floats are interpolated in their own type. Integers are interpolated as an offset from `lo`
+/// in 128-bit arithmetic, so the result never overflows, and the offset is rounded half to
+/// even. A `frac` of 0.5 is exact for any span. For other fractions the offset is computed in
+/// `f64`, which is exact for spans up to 2^53 and may be off by a few units beyond that; the
+/// result still lies between `lo` and `hi`. A `hi` smaller than `lo` is interpolated on the
+/// same line.
+pub trait Lerp: Copy {
+    /// Interpolate from `lo` to `hi`, with `frac` in `[0, 1]`.
+    fn lerp(lo: Self, hi: Self, frac: f64) -> Self;
+}
+
+macro_rules! impl_lerp_float {
+    ($($t:ty),*) => {
+        $(
+            impl Lerp for $t {
+                #[inline]
+                fn lerp(lo: Self, hi: Self, frac: f64) -> Self {
+                    lerp_float(lo, hi, frac)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lerp_int {
+    ($($t:ty),*) => {
+        $(
+            impl Lerp for $t {
+                #[inline]
+                fn lerp(lo: Self, hi: Self, frac: f64) -> Self {
+                    let (lo_, hi_) = (lo as i128, hi as i128);
+                    // The wrapping difference is the exact distance, as it always fits in `u128`.
+                    let out = if lo_ <= hi_ {
+                        lo_.wrapping_add(lerp_offset(hi_.wrapping_sub(lo_) as u128, frac) as i128)
+                    } else {
+                        lo_.wrapping_sub(lerp_offset(lo_.wrapping_sub(hi_) as u128, frac) as i128)
+                    };
+                    out as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_float!(f32, f64);
+impl_lerp_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// The offset `span * frac`, rounded half to even and at most `span`.
+fn lerp_offset(span: u128, frac: f64) -> u128 {
+    if frac == 0.0 {
+        0
+    } else if frac == 1.0 {
+        span
+    } else if frac == 0.5 {
+        let half = span / 2;
+        // A tie rounds to even.
+        half + (span % 2 == 1 && half % 2 == 1) as u128
+    } else {
+        // Rounding of `span` to `f64` may overshoot by a few units for very large spans.
+        (round_half_to_even(span as f64 * frac) as u128).min(span)
+    }
+}
+
+/// Cast `x` to `T`, with a `ComputeError` naming both types if `x` can't be represented in `T`.
+pub fn try_cast_num<T: NumCast, S: NumCast + Display + Copy>(x: S) -> PolarsResult<T> {
+    T::from(x).ok_or_else(|| {
+        polars_err!(
+            ComputeError: "cannot cast {} of type {} to {}",
+            x, std::any::type_name::<S>(), std::any::type_name::<T>()
+        )
+    })
+}
+
+fn round_half_to_even(v: f64) -> f64 {
+    let rounded = v.round();
+    if (rounded - v).abs() == 0.5 {
+        2.0 * (v / 2.0).round()
+    } else {
+        rounded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lerp_float() {
+        assert_eq!(lerp(1.0f64, 2.0, 0.25), 1.25);
+        assert_eq!(lerp(1.0f32, 2.0, 0.5), 1.5);
+        // `f32` is interpolated in `f32`.
+        assert_eq!(lerp(0.1f32, 0.7, 0.3), 0.1 + (0.7 - 0.1) * 0.3f32);
+        // These differ in the last bit from `lo * (1 - frac) + hi * frac`.
+        assert_eq!(lerp(0.1f64, 0.3, 0.3), 0.16);
+        assert_eq!(lerp(0.1f64, 0.7, 0.7), 0.52);
+        assert_eq!(lerp_float(0.1f64, 0.7, 0.7), 0.52);
+        assert_eq!(lerp(-0.0f64, 0.0, 0.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(lerp(f64::MAX, f64::MAX, 0.3), f64::MAX);
+        assert_eq!(lerp(3.0f64, 1.0, 0.25), 2.5);
+        assert_eq!(lerp(1.0f64, 2.0, 1.5), 2.0);
+        assert!(lerp(f64::NAN, 1.0, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_lerp_integer_rounding() {
+        // Ties round to even.
+        assert_eq!(lerp(0u8, 5, 0.5), 2);
+        assert_eq!(lerp(0u8, 3, 0.5), 2);
+        assert_eq!(lerp(0i32, 10, 0.26), 3);
+        assert_eq!(lerp(0i32, 10, 0.0), 0);
+        assert_eq!(lerp(0i32, 10, 1.0), 10);
+        assert_eq!(lerp(u8::MAX, u8::MAX, 0.7), u8::MAX);
+    }
+
+    #[test]
+    fn test_lerp_large_magnitudes() {
+        let top = u64::MAX;
+        assert_eq!(lerp(top - 10, top, 0.5), top - 5);
+        assert_eq!(lerp(top - 3, top, 0.5), top - 1);
+        assert_eq!(lerp(top - 1, top, 0.9), top);
+        assert_eq!(lerp(0, top, 1.0), top);
+
+        // Negative spans of i64.
+        assert_eq!(lerp(-10i64, -3, 0.5), -6);
+        assert_eq!(lerp(i64::MIN, i64::MIN + 4, 0.25), i64::MIN + 1);
+        assert_eq!(lerp(i64::MIN, i64::MAX, 0.5), 0);
+        assert_eq!(lerp(i64::MIN, i64::MAX, 1.0), i64::MAX);
+        assert_eq!(lerp(i64::MIN, 0, 0.5), i64::MIN / 2);
+    }
+
+    #[test]
+    fn test_lerp_i128() {
+        let max = i128::MAX;
+        // The midpoint is exact for any span.
+        assert_eq!(lerp(i128::MIN, max, 0.5), 0);
+        assert_eq!(lerp(max - 3, max, 0.5), max - 1);
+        assert_eq!(lerp(i128::MIN, max - 10, 0.5), -6);
+        assert_eq!(lerp(i128::MIN, max, 1.0), max);
+        // Other fractions go through `f64`, but stay between the bounds.
+        assert_eq!(lerp(i128::MIN, max, 0.25), i128::MIN + (1 << 126));
+        assert_eq!(lerp(max - 1, max, 0.99), max);
+    }
+
+    #[test]
+    fn test_lerp_reversed_bounds() {
+        assert_eq!(lerp(10i32, 0, 0.25), 8);
+        assert_eq!(lerp(10i32, 0, 1.0), 0);
+        assert_eq!(lerp(u64::MAX, 0, 1.0), 0);
+        assert_eq!(lerp(u64::MAX, u64::MAX - 4, 0.5), u64::MAX - 2);
+    }
+
+    /// A number type that can't represent anything.
+    #[derive(Clone, Copy, Debug)]
+    struct Unrepresentable;
+
+    impl num_traits::ToPrimitive for Unrepresentable {
+        fn to_i64(&self) -> Option<i64> {
+            None
+        }
+        fn to_u64(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    impl NumCast for Unrepresentable {
+        fn from<N: num_traits::ToPrimitive>(_: N) -> Option<Self> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_try_cast_num() {
+        assert_eq!(try_cast_num::<f32, _>(0.5f64).unwrap(), 0.5);
+        assert_eq!(try_cast_num::<u8, _>(200i64).unwrap(), 200);
+        assert!(try_cast_num::<u8, _>(-1i64).is_err());
+
+        let err = try_cast_num::<Unrepresentable, _>(0.5f64).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("cannot cast 0.5 of type f64"), "{msg}");
+        assert!(msg.contains("Unrepresentable"), "{msg}");
+    }
+}
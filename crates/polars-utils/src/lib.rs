@@ -5,6 +5,7 @@ pub mod atomic;
 pub mod cache;
 pub mod cell;
 pub mod contention_pool;
+pub mod count_min;
 mod error;
 pub mod functions;
 pub mod hashing;
@@ -2,6 +2,8 @@ use core::slice::SliceIndex;
 use std::cmp::Ordering;
 use std::mem::MaybeUninit;
 
+use crate::total_ord::TotalOrd;
+
 pub trait Extrema<T> {
     fn min_value(&self) -> Option<&T>;
     fn max_value(&self) -> Option<&T>;
@@ -42,6 +44,45 @@ impl<T: PartialOrd + Copy> SortedSlice<T> for [T] {
     }
 }
 
+/// Binary search for `value` in a `slice` that is sorted by [`TotalOrd`], so it may contain NaN.
+///
+/// Like [`slice::binary_search`], any match is returned if there are several. Use
+/// [`binary_search_total_leftmost`] or [`binary_search_total_rightmost`] to get the first or
+/// the last one.
+#[inline]
+pub fn binary_search_total<T: TotalOrd>(slice: &[T], value: &T) -> Result<usize, usize> {
+    slice.binary_search_by(|x| x.tot_cmp(value))
+}
+
+/// The number of elements of a `slice` sorted by [`TotalOrd`] that are less than `value`, the
+/// leftmost position at which `value` can be inserted while keeping the slice sorted.
+#[inline]
+pub fn partition_point_total<T: TotalOrd>(slice: &[T], value: &T) -> usize {
+    slice.partition_point(|x| x.tot_lt(value))
+}
+
+/// [`binary_search_total`] that returns the first match.
+#[inline]
+pub fn binary_search_total_leftmost<T: TotalOrd>(slice: &[T], value: &T) -> Result<usize, usize> {
+    let idx = partition_point_total(slice, value);
+    match slice.get(idx) {
+        Some(x) if x.tot_eq(value) => Ok(idx),
+        _ => Err(idx),
+    }
+}
+
+/// [`binary_search_total`] that returns the last match. If there is none, the error holds the
+/// rightmost position at which `value` can be inserted.
+#[inline]
+pub fn binary_search_total_rightmost<T: TotalOrd>(slice: &[T], value: &T) -> Result<usize, usize> {
+    let idx = slice.partition_point(|x| x.tot_le(value));
+    if idx > 0 && slice[idx - 1].tot_eq(value) {
+        Ok(idx - 1)
+    } else {
+        Err(idx)
+    }
+}
+
 pub trait GetSaferUnchecked<T> {
     /// # Safety
     ///
@@ -102,3 +143,64 @@ impl<T> Slice2Uninit<T> for [T] {
         unsafe { std::slice::from_raw_parts(self.as_ptr() as *const MaybeUninit<T>, self.len()) }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// All sorted slices of up to `len` values drawn from `alphabet`, which must be sorted.
+    fn sorted_slices(alphabet: &[f64], len: usize) -> Vec<Vec<f64>> {
+        let mut out = vec![vec![]];
+        let mut last = vec![(vec![], 0)];
+        for _ in 0..len {
+            let mut next = vec![];
+            for (slice, start) in last {
+                for (i, v) in alphabet.iter().enumerate().skip(start) {
+                    let mut slice: Vec<f64> = slice.clone();
+                    slice.push(*v);
+                    next.push((slice, i));
+                }
+            }
+            out.extend(next.iter().map(|(slice, _)| slice.clone()));
+            last = next;
+        }
+        out
+    }
+
+    #[test]
+    fn test_binary_search_total() {
+        // Sorted by the total order, in which -0.0 equals 0.0 and NaN is the largest value.
+        let alphabet = [
+            f64::NEG_INFINITY,
+            -1.0,
+            -0.0,
+            0.0,
+            1.0,
+            f64::INFINITY,
+            f64::NAN,
+            -f64::NAN,
+        ];
+        let needles = alphabet.iter().copied().chain([-2.0, 0.5, 2.0]);
+        let needles = needles.collect::<Vec<_>>();
+
+        for slice in sorted_slices(&alphabet, 5) {
+            for v in &needles {
+                let lt = slice.iter().filter(|x| x.tot_lt(v)).count();
+                let le = slice.iter().filter(|x| x.tot_le(v)).count();
+                assert_eq!(partition_point_total(&slice, v), lt);
+
+                let first = slice.iter().position(|x| x.tot_eq(v));
+                let last = slice.iter().rposition(|x| x.tot_eq(v));
+                assert_eq!(binary_search_total_leftmost(&slice, v), first.ok_or(lt));
+                assert_eq!(binary_search_total_rightmost(&slice, v), last.ok_or(le));
+                match binary_search_total(&slice, v) {
+                    Ok(i) => assert!(slice[i].tot_eq(v)),
+                    Err(i) => {
+                        assert!(first.is_none());
+                        assert_eq!(i, lt);
+                    },
+                }
+            }
+        }
+    }
+}
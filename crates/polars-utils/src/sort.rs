@@ -1,6 +1,7 @@
 use rayon::prelude::*;
 use rayon::ThreadPool;
 
+use crate::total_ord::TotalOrd;
 use crate::IdxSize;
 
 /// This is a perfect sort particularly useful for an arg_sort of an arg_sort
@@ -72,3 +73,197 @@ pub unsafe fn perfect_sort(
     // all elements are written
     out.set_len(idx.len());
 }
+
+/// Arg sort `values` in ascending order.
+///
+/// The `(index, value)` pairs are sorted in `scratch`, so that repeated calls can reuse its
+/// allocation. Values are compared with [`TotalOrd`], which puts NaN after all other floats.
+///
+/// The sort is stable: the indices of equal values are returned in increasing order. Callers
+/// that break ties on the index rely on this. Use [`arg_sort_ascending_unstable`] if the order
+/// of ties does not matter.
+pub fn arg_sort_ascending<T, I>(
+    values: I,
+    scratch: &mut Vec<(IdxSize, T)>,
+) -> impl ExactSizeIterator<Item = IdxSize> + '_
+where
+    T: TotalOrd + Copy,
+    I: IntoIterator<Item = T>,
+{
+    fill_scratch(values, scratch);
+    scratch.sort_by(|a, b| a.1.tot_cmp(&b.1));
+    scratch.iter().map(|(idx, _)| *idx)
+}
+
+/// Arg sort `values` in ascending order, without a guarantee on the order of equal values.
+///
+/// This is faster than [`arg_sort_ascending`] and does not allocate besides `scratch`.
+pub fn arg_sort_ascending_unstable<T, I>(
+    values: I,
+    scratch: &mut Vec<(IdxSize, T)>,
+) -> impl ExactSizeIterator<Item = IdxSize> + '_
+where
+    T: TotalOrd + Copy,
+    I: IntoIterator<Item = T>,
+{
+    fill_scratch(values, scratch);
+    scratch.sort_unstable_by(|a, b| a.1.tot_cmp(&b.1));
+    scratch.iter().map(|(idx, _)| *idx)
+}
+
+/// Arg sort `values` in descending order.
+///
+/// This is the reverse order of [`arg_sort_ascending`], except that equal values still
+/// keep their original order.
+pub fn arg_sort_descending<T, I>(
+    values: I,
+    scratch: &mut Vec<(IdxSize, T)>,
+) -> impl ExactSizeIterator<Item = IdxSize> + '_
+where
+    T: TotalOrd + Copy,
+    I: IntoIterator<Item = T>,
+{
+    fill_scratch(values, scratch);
+    scratch.sort_by(|a, b| b.1.tot_cmp(&a.1));
+    scratch.iter().map(|(idx, _)| *idx)
+}
+
+/// The index of the `n`-th smallest value, or `None` if there are at most `n` values.
+///
+/// This gives the same index as the `n`-th element of [`arg_sort_ascending`], but only
+/// partially sorts `scratch`.
+pub fn arg_select_nth<T, I>(values: I, n: usize, scratch: &mut Vec<(IdxSize, T)>) -> Option<IdxSize>
+where
+    T: TotalOrd + Copy,
+    I: IntoIterator<Item = T>,
+{
+    fill_scratch(values, scratch);
+    if n >= scratch.len() {
+        return None;
+    }
+    // Break ties on the index, so that equal values are ordered as in a stable sort.
+    let (_, nth, _) =
+        scratch.select_nth_unstable_by(n, |a, b| a.1.tot_cmp(&b.1).then(a.0.cmp(&b.0)));
+    Some(nth.0)
+}
+
+fn fill_scratch<T, I>(values: I, scratch: &mut Vec<(IdxSize, T)>)
+where
+    I: IntoIterator<Item = T>,
+{
+    scratch.clear();
+    scratch.extend(
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, v)| (idx as IdxSize, v)),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::total_ord::TotalEq;
+
+    // A small xorshift generator, so the tests are deterministic.
+    fn random_values(seed: u64, len: usize) -> Vec<f64> {
+        let mut state = seed.max(1);
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                match state % 13 {
+                    0 => f64::NAN,
+                    // Few distinct values, so there are plenty of ties.
+                    v => (v as f64 - 6.0) * 0.5,
+                }
+            })
+            .collect()
+    }
+
+    fn full_arg_sort(values: &[f64], descending: bool) -> Vec<IdxSize> {
+        let mut idx = (0..values.len() as IdxSize).collect::<Vec<_>>();
+        idx.sort_by(|a, b| {
+            let (a, b) = (&values[*a as usize], &values[*b as usize]);
+            if descending {
+                b.tot_cmp(a)
+            } else {
+                a.tot_cmp(b)
+            }
+        });
+        idx
+    }
+
+    #[test]
+    fn test_arg_sort_against_full_sort() {
+        let mut scratch = Vec::new();
+        for seed in 0..50 {
+            let values = random_values(seed, seed as usize * 3);
+
+            let asc = arg_sort_ascending(values.iter().copied(), &mut scratch).collect::<Vec<_>>();
+            assert_eq!(asc, full_arg_sort(&values, false));
+
+            let desc =
+                arg_sort_descending(values.iter().copied(), &mut scratch).collect::<Vec<_>>();
+            assert_eq!(desc, full_arg_sort(&values, true));
+
+            let unstable = arg_sort_ascending_unstable(values.iter().copied(), &mut scratch)
+                .collect::<Vec<_>>();
+            assert_sorted_permutation(&values, &unstable);
+
+            for n in 0..=values.len() {
+                let nth = arg_select_nth(values.iter().copied(), n, &mut scratch);
+                assert_eq!(nth, asc.get(n).copied(), "seed {seed}, n {n}");
+            }
+        }
+    }
+
+    fn assert_sorted_permutation(values: &[f64], idx: &[IdxSize]) {
+        let mut seen = vec![false; values.len()];
+        for i in idx {
+            assert!(!std::mem::replace(&mut seen[*i as usize], true));
+        }
+        assert!(seen.iter().all(|s| *s));
+        assert!(idx
+            .windows(2)
+            .all(|w| values[w[0] as usize].tot_le(&values[w[1] as usize])));
+    }
+
+    #[test]
+    fn test_arg_sort_stable_with_duplicates() {
+        let mut scratch = Vec::new();
+        // Only three distinct values (and NaN) over a long input, so every value has many ties.
+        let values = (0..1000)
+            .map(|i| match i * 7 % 4 {
+                0 => f64::NAN,
+                v => v as f64,
+            })
+            .collect::<Vec<_>>();
+
+        let asc = arg_sort_ascending(values.iter().copied(), &mut scratch).collect::<Vec<_>>();
+        assert_sorted_permutation(&values, &asc);
+        for w in asc.windows(2) {
+            let (a, b) = (w[0] as usize, w[1] as usize);
+            if values[a].tot_eq(&values[b]) {
+                assert!(a < b, "ties at {a} and {b} are not in index order");
+            }
+        }
+
+        let desc = arg_sort_descending(values.iter().copied(), &mut scratch).collect::<Vec<_>>();
+        for w in desc.windows(2) {
+            let (a, b) = (w[0] as usize, w[1] as usize);
+            if values[a].tot_eq(&values[b]) {
+                assert!(a < b, "ties at {a} and {b} are not in index order");
+            }
+        }
+
+        // All values equal: the stable arg sort is the identity.
+        let asc = arg_sort_ascending(std::iter::repeat(1i32).take(500), &mut scratch);
+        assert!(asc.eq(0..500));
+
+        let unstable =
+            arg_sort_ascending_unstable(values.iter().copied(), &mut scratch).collect::<Vec<_>>();
+        assert_sorted_permutation(&values, &unstable);
+    }
+}
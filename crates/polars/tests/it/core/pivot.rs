@@ -1,6 +1,6 @@
 use polars::export::chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{pivot, pivot_stable, AggFunc, PivotAgg};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -245,3 +245,56 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cross_tab() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["y", "x", "x", "y", "x", "z"],
+        "b" => ["p", "p", "q", "q", "p", "q"],
+        "v" => [2, 1, 3, 4, 5, 6]
+    ]?;
+
+    let out = df.cross_tab("a", "b", "v", AggFunc::Count)?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "p" => [2 as IdxSize, 1, 0],
+        "q" => [1 as IdxSize, 1, 1]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df.cross_tab("a", "b", "v", AggFunc::Sum)?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "p" => [Some(6), Some(2), None],
+        "q" => [3, 4, 6]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df.cross_tab("a", "b", "v", AggFunc::Mean)?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "p" => [Some(3.0), Some(2.0), None],
+        "q" => [3.0, 4.0, 6.0]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df.cross_tab("a", "b", "v", AggFunc::Min)?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "p" => [Some(1), Some(2), None],
+        "q" => [3, 4, 6]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df.cross_tab("b", "a", "v", AggFunc::Max)?;
+    let expected = df![
+        "b" => ["p", "q"],
+        "x" => [5, 3],
+        "y" => [2, 4],
+        "z" => [None, Some(6)]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    assert!(df.cross_tab("a", "a", "v", AggFunc::Count).is_err());
+    Ok(())
+}
@@ -425,3 +425,185 @@ fn test_median_quantile_types() {
     assert_eq!(*rol_quantile.dtype(), DataType::Float64);
     assert_eq!(*rol_quantile_weighted.dtype(), DataType::Float64);
 }
+
+#[test]
+fn test_frame_rolling_quantile() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => [4.0, 3.0, 2.0, 1.0],
+        "c" => ["w", "x", "y", "z"]
+    ]?;
+
+    let out = df.rolling_quantile(2, 0.5, ["a", "b", "c"], false)?;
+    assert_eq!(
+        out.get_column_names(),
+        &["a_rolling_quantile", "b_rolling_quantile", "c"]
+    );
+    assert_eq!(
+        Vec::from(out.column("a_rolling_quantile")?.f64()?),
+        &[None, Some(1.5), Some(2.5), Some(3.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b_rolling_quantile")?.f64()?),
+        &[None, Some(3.5), Some(2.5), Some(1.5)]
+    );
+    assert!(out.column("c")?.equals(df.column("c")?));
+
+    assert!(df.rolling_quantile(2, 0.5, ["a", "c"], true).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rolling_invalid_options() {
+    // An empty series would skip the kernels altogether, so the options must be checked first.
+    for s in [
+        Float64Chunked::new("foo", &[1.0, 2.0, 3.0]).into_series(),
+        Float64Chunked::new("foo", &[] as &[f64]).into_series(),
+    ] {
+        let fixed = |window_size, min_periods, weights| RollingOptionsImpl {
+            window_size: Duration::new(window_size),
+            min_periods,
+            weights,
+            ..Default::default()
+        };
+        assert!(s.rolling_mean(fixed(0, 1, None)).is_err());
+        // A `min_periods` of 0 is allowed, e.g. `rolling_cov` relies on it.
+        assert!(s.rolling_mean(fixed(2, 0, None)).is_ok());
+        assert!(s.rolling_mean(fixed(2, 3, None)).is_err());
+        assert!(s.rolling_mean(fixed(2, 1, Some(vec![1.0]))).is_err());
+        assert!(s.rolling_mean(fixed(2, 1, Some(vec![1.0, 1.0]))).is_ok());
+
+        let by = (0..s.len() as i64).collect::<Vec<_>>();
+        let dynamic = |window_size: &str, center| RollingOptionsImpl {
+            window_size: Duration::parse(window_size),
+            center,
+            by: Some(&by),
+            tu: Some(TimeUnit::Milliseconds),
+            closed_window: Some(ClosedWindow::Right),
+            ..Default::default()
+        };
+        assert!(s.rolling_mean(dynamic("-1d", false)).is_err());
+        // `center` and `weights` used to be ignored silently for a temporal window.
+        assert!(s.rolling_mean(dynamic("1d", true)).is_err());
+        let mut options = dynamic("1d", false);
+        options.weights = Some(vec![1.0]);
+        assert!(s.rolling_mean(options).is_err());
+        let mut options = dynamic("1d", false);
+        options.by = None;
+        assert!(s.rolling_mean(options).is_err());
+        assert!(s.rolling_mean(dynamic("1d", false)).is_ok());
+    }
+}
+
+#[test]
+fn test_rolling_quantile_fixed_dtypes() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(4i32), Some(1), None, Some(3), Some(7)]);
+    let options = RollingOptionsFixedWindow {
+        window_size: 2,
+        min_periods: 1,
+        ..Default::default()
+    };
+    let expected = [Some(4.0), Some(2.5), Some(1.0), Some(3.0), Some(5.0)];
+
+    let mut dtypes = vec![
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Float32,
+        DataType::Float64,
+    ];
+    #[cfg(feature = "dtype-u8")]
+    dtypes.push(DataType::UInt8);
+    #[cfg(feature = "dtype-u16")]
+    dtypes.push(DataType::UInt16);
+    #[cfg(feature = "dtype-i8")]
+    dtypes.push(DataType::Int8);
+    #[cfg(feature = "dtype-i16")]
+    dtypes.push(DataType::Int16);
+
+    for dtype in dtypes {
+        let out = s.cast(&dtype)?.rolling_quantile_fixed(
+            options.clone(),
+            0.5,
+            QuantileInterpolOptions::Linear,
+        )?;
+        if dtype == DataType::Float32 {
+            assert_eq!(out.dtype(), &DataType::Float32);
+        } else {
+            assert_eq!(out.dtype(), &DataType::Float64, "{dtype}");
+        }
+        let out = out.cast(&DataType::Float64)?;
+        assert_eq!(Vec::from(out.f64()?), &expected, "{dtype}");
+    }
+
+    let dtype = DataType::Duration(TimeUnit::Milliseconds);
+    let out = s
+        .cast(&DataType::Int64)?
+        .cast(&dtype)?
+        .rolling_quantile_fixed(options, 0.5, QuantileInterpolOptions::Linear)?;
+    assert_eq!(out.dtype(), &dtype);
+    assert_eq!(
+        Vec::from(out.to_physical_repr().i64()?),
+        &[Some(4), Some(2), Some(1), Some(3), Some(5)]
+    );
+
+    assert!(Series::new("a", &["x"])
+        .rolling_quantile_fixed(Default::default(), 0.5, QuantileInterpolOptions::Linear)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rolling_quantile_dyn() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(4i32), Some(1), None, Some(3), Some(7)]);
+    let options = RollingOptionsFixedWindow {
+        window_size: 2,
+        min_periods: 1,
+        ..Default::default()
+    };
+
+    for dtype in [
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Float32,
+        DataType::Float64,
+    ] {
+        let s = s.cast(&dtype)?;
+        for (interpol, expected) in [
+            (
+                QuantileInterpolOptions::Lower,
+                [Some(4.0), Some(1.0), Some(1.0), Some(3.0), Some(3.0)],
+            ),
+            (
+                QuantileInterpolOptions::Higher,
+                [Some(4.0), Some(4.0), Some(1.0), Some(3.0), Some(7.0)],
+            ),
+        ] {
+            let out = rolling_quantile_dyn(&s, options.clone(), 0.5, interpol)?;
+            assert_eq!(out.dtype(), &dtype, "{dtype}");
+            let out = out.cast(&DataType::Float64)?;
+            assert_eq!(Vec::from(out.f64()?), &expected, "{dtype}");
+        }
+
+        let out = rolling_quantile_dyn(&s, options.clone(), 0.5, Linear)?;
+        let expected_dtype = match dtype {
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        };
+        assert_eq!(out.dtype(), &expected_dtype, "{dtype}");
+        let out = out.cast(&DataType::Float64)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(4.0), Some(2.5), Some(1.0), Some(3.0), Some(5.0)],
+            "{dtype}"
+        );
+    }
+
+    // Integers beyond the exact range of f64 are not rounded.
+    let big = i64::MAX - 1;
+    let s = Series::new("a", &[big, big - 1, big]);
+    let out = rolling_quantile_dyn(&s, options, 0.5, QuantileInterpolOptions::Higher)?;
+    assert_eq!(Vec::from(out.i64()?), &[Some(big), Some(big), Some(big)]);
+    Ok(())
+}
@@ -36,6 +36,28 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_null_value() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [Some("x"), Some("y"), None],
+    ]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_header(true)
+        .with_null_value("NA")
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("a,b\n1,x\nNA,y\n3,NA\n", csv);
+
+    let out = CsvReader::new(Cursor::new(buf))
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+        .finish()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -741,6 +763,31 @@ fn test_ignore_parse_dates() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_dtypes_slice_headerless() -> PolarsResult<()> {
+    let csv = "1,2,a
+3,4,b
+5,6,c";
+
+    use DataType::*;
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .has_header(false)
+        .with_dtypes_slice(Some(&[Float64, Utf8]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["column_1", "column_2", "column_3"]);
+    assert_eq!(df.dtypes(), &[Float64, Utf8, Utf8]);
+    assert_eq!(df.column("column_1")?.f64()?.get(2), Some(5.0));
+
+    let file = Cursor::new(csv);
+    let out = CsvReader::new(file)
+        .has_header(false)
+        .with_dtypes_slice(Some(&[Int64, Int64, Utf8, Utf8]))
+        .finish();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_projection_and_quoting() -> PolarsResult<()> {
     let csv = "a,b,c,d
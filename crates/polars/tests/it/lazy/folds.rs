@@ -25,3 +25,49 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+fn test_horizontal_strict() -> PolarsResult<()> {
+    let df = df![
+        "a" => [true, false, true],
+        "b" => [true, true, false],
+        "c" => [1, 0, 2]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            polars_lazy::dsl::all_horizontal_strict([col("a"), col("b")])?,
+            polars_lazy::dsl::any_horizontal_strict([col("a"), col("b")])?,
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("all")?.bool()?),
+        &[Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("any")?.bool()?),
+        &[Some(true), Some(true), Some(true)]
+    );
+
+    // The non-strict versions cast the integers to boolean.
+    let out = df
+        .clone()
+        .lazy()
+        .select([polars_lazy::dsl::all_horizontal([col("a"), col("c")])?])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("all")?.bool()?),
+        &[Some(true), Some(false), Some(true)]
+    );
+
+    for expr in [
+        polars_lazy::dsl::all_horizontal_strict([col("a"), col("c")])?,
+        polars_lazy::dsl::any_horizontal_strict([col("a"), col("c")])?,
+    ] {
+        let err = df.clone().lazy().select([expr]).collect().unwrap_err();
+        assert!(err.to_string().contains("column 'c' of dtype i32"));
+    }
+    Ok(())
+}
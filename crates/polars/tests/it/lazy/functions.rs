@@ -26,3 +26,316 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-time"))]
+fn test_time_ranges() {
+    let hour = 3_600_000_000_000i64;
+    let df = df![
+        "start" => [0, hour, hour],
+        "end" => [2 * hour, hour, 0],
+    ]
+    .unwrap();
+
+    let out = df
+        .lazy()
+        .select([time_ranges(
+            col("start").cast(DataType::Time),
+            col("end").cast(DataType::Time),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+        )
+        .alias("rng")])
+        .collect();
+    // The last row has `end` before `start`.
+    assert!(out.is_err());
+
+    let df = df![
+        "start" => [0, hour],
+        "end" => [2 * hour, hour],
+    ]
+    .unwrap();
+    let out = df
+        .lazy()
+        .select([time_ranges(
+            col("start").cast(DataType::Time),
+            col("end").cast(DataType::Time),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+        )
+        .alias("rng")])
+        .collect()
+        .unwrap();
+    let rng = out.column("rng").unwrap();
+    assert_eq!(rng.dtype(), &DataType::List(Box::new(DataType::Time)));
+
+    let rng = rng.list().unwrap();
+    let first = rng
+        .get_as_series(0)
+        .unwrap()
+        .to_physical_repr()
+        .into_owned();
+    assert_eq!(
+        Vec::from(first.i64().unwrap()),
+        &[Some(0), Some(hour), Some(2 * hour)]
+    );
+    let second = rng
+        .get_as_series(1)
+        .unwrap()
+        .to_physical_repr()
+        .into_owned();
+    assert_eq!(Vec::from(second.i64().unwrap()), &[Some(hour)]);
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-time"))]
+fn test_time_ranges_all_empty() {
+    let hour = 3_600_000_000_000i64;
+    let df = df![
+        "start" => [0, hour],
+        "end" => [0, hour],
+    ]
+    .unwrap();
+
+    let out = df
+        .lazy()
+        .select([time_ranges(
+            col("start").cast(DataType::Time),
+            col("end").cast(DataType::Time),
+            Duration::parse("1h"),
+            ClosedWindow::None,
+        )
+        .alias("rng")])
+        .collect()
+        .unwrap();
+    let rng = out.column("rng").unwrap();
+    assert_eq!(rng.dtype(), &DataType::List(Box::new(DataType::Time)));
+    assert_eq!(rng.null_count(), 0);
+    assert!(rng
+        .list()
+        .unwrap()
+        .into_iter()
+        .all(|s| s.unwrap().is_empty()));
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-time"))]
+fn test_time_ranges_packed() -> PolarsResult<()> {
+    let hour = 3_600_000_000_000i64;
+    let bounds = Series::new(
+        "bounds",
+        &[
+            Series::new("", &[0, 2 * hour]),
+            Series::new("", &[hour, hour]),
+        ],
+    )
+    .cast(&DataType::List(Box::new(DataType::Time)))?;
+    let df = DataFrame::new(vec![bounds])?;
+
+    let out = df
+        .lazy()
+        .select([
+            time_ranges_packed(col("bounds"), Duration::parse("1h"), ClosedWindow::Both)
+                .alias("rng"),
+        ])
+        .collect()?;
+    let rng = out.column("rng")?;
+    assert_eq!(rng.dtype(), &DataType::List(Box::new(DataType::Time)));
+
+    let rng = rng.list()?;
+    let first = rng
+        .get_as_series(0)
+        .unwrap()
+        .to_physical_repr()
+        .into_owned();
+    assert_eq!(
+        Vec::from(first.i64()?),
+        &[Some(0), Some(hour), Some(2 * hour)]
+    );
+    let second = rng
+        .get_as_series(1)
+        .unwrap()
+        .to_physical_repr()
+        .into_owned();
+    assert_eq!(Vec::from(second.i64()?), &[Some(hour)]);
+
+    // Rows must be `[start, end]` pairs.
+    let bounds = Series::new("bounds", &[Series::new("", &[0, hour, 2 * hour])])
+        .cast(&DataType::List(Box::new(DataType::Time)))?;
+    let out = DataFrame::new(vec![bounds])?
+        .lazy()
+        .select([time_ranges_packed(
+            col("bounds"),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+        )])
+        .collect();
+    assert!(out.is_err());
+
+    // The bounds must be a list.
+    let err = df!["bounds" => [0i64]]?
+        .lazy()
+        .select([time_ranges_packed(
+            col("bounds"),
+            Duration::parse("1h"),
+            ClosedWindow::Both,
+        )])
+        .collect()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("packed time range bounds must be a list of `[start, end]` pairs, got i64"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-datetime", feature = "timezones"))]
+fn test_datetime_ranges_inner_dtype() -> PolarsResult<()> {
+    let day = 86_400_000i64;
+    let df = df![
+        "start" => [0, day],
+        "end" => [day, 3 * day],
+    ]?;
+
+    let tz = "Europe/Amsterdam".to_string();
+    let out = df
+        .lazy()
+        .select([datetime_ranges(
+            col("start").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            col("end").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            Duration::parse("1d"),
+            ClosedWindow::Both,
+            None,
+            Some(tz.clone()),
+        )
+        .alias("rng")])
+        .collect()?;
+    let rng = out.column("rng")?;
+    let dtype = DataType::Datetime(TimeUnit::Milliseconds, Some(tz));
+    assert_eq!(rng.dtype(), &DataType::List(Box::new(dtype.clone())));
+
+    let second = rng.list()?.get_as_series(1).unwrap();
+    assert_eq!(second.dtype(), &dtype);
+    assert_eq!(second.len(), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_str_count_matches() -> PolarsResult<()> {
+    let df = df![
+        "text" => [Some("a,b,c"), Some("abc"), Some("a.b"), None, Some("")]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("text")
+                .str()
+                .count_matches(lit(","), true)
+                .alias("commas"),
+            col("text")
+                .str()
+                .count_matches(lit("."), true)
+                .alias("dots"),
+            col("text")
+                .str()
+                .count_matches(lit("[a-z]"), false)
+                .alias("letters"),
+        ])
+        .collect()?;
+
+    let commas = out.column("commas")?;
+    assert_eq!(commas.dtype(), &DataType::UInt32);
+    assert_eq!(
+        Vec::from(commas.u32()?),
+        &[Some(2), Some(0), Some(0), None, Some(0)]
+    );
+    // A literal "." only counts actual dots.
+    assert_eq!(
+        Vec::from(out.column("dots")?.u32()?),
+        &[Some(0), Some(0), Some(1), None, Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("letters")?.u32()?),
+        &[Some(3), Some(3), Some(2), None, Some(0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "range", not(feature = "bigidx")))]
+fn test_int_range_cast_error_context() {
+    // The end doesn't fit in the `u32` index type of the start.
+    let err = DataFrame::default()
+        .lazy()
+        .select([int_range(lit(0u32), lit(u64::MAX), 1)])
+        .collect()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("`end`"), "{err}");
+    assert!(err.contains("`int_range`"), "{err}");
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_int_range_casts_bounds() -> PolarsResult<()> {
+    // Bounds that are not integers are cast, as long as they fit.
+    let out = DataFrame::default()
+        .lazy()
+        .select([int_range(lit(1.0f64), lit(4u8), 1).alias("r")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("r")?.i64()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+fn test_dst_offset_minutes() -> PolarsResult<()> {
+    // 2023-01-01 and 2023-07-01 00:00 UTC.
+    let df = df!["t" => [1_672_531_200_000i64, 1_688_169_600_000]]?;
+    let t = col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([t.clone().dt().dst_offset_minutes("Europe/Amsterdam")])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("t")?.i32()?), &[Some(0), Some(60)]);
+
+    let err = df
+        .lazy()
+        .select([t.dt().dst_offset_minutes("Mars/Olympus")])
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::ComputeError(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "cov", feature = "rolling_window"))]
+fn test_rolling_cov() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1.0, 2.0, 3.0],
+        "y" => [1.0, 2.0, 4.0],
+    ]?;
+    let options = RollingCovOptions {
+        window_size: 2,
+        min_periods: 2,
+        ddof: 1,
+    };
+    let out = df
+        .lazy()
+        .select([rolling_cov(col("x"), col("y"), options).alias("cov")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("cov")?.f64()?),
+        &[None, Some(0.5), Some(1.0)]
+    );
+    Ok(())
+}
@@ -1,6 +1,9 @@
-use polars::export::chrono::NaiveDate;
+use polars::export::chrono::{NaiveDate, NaiveTime};
 use polars::prelude::*;
-use polars::time::{date_range, ClosedWindow, Duration};
+use polars::time::{
+    date_range, date_range_dates, time_range, time_range_n, time_range_with_filter,
+    time_range_with_midpoints, ClosedWindow, Duration,
+};
 
 #[test]
 fn test_time_units_9413() {
@@ -77,3 +80,186 @@ Series: 'date' [datetime[ns]]
     assert_eq!(result, expected);
     assert_eq!(result, expected);
 }
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_time_range_with_filter() {
+    let start = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+    let end = NaiveTime::from_hms_opt(6, 0, 0).unwrap();
+
+    // Keep every other point of an hourly range.
+    let mut i = 0;
+    let out = time_range_with_filter(
+        "time",
+        start,
+        end,
+        Duration::parse("1h"),
+        ClosedWindow::Both,
+        |_| {
+            i += 1;
+            i % 2 == 1
+        },
+    )
+    .unwrap();
+    let hour = 3_600_000_000_000i64;
+    assert_eq!(
+        out.cont_slice().unwrap(),
+        &[0, 2 * hour, 4 * hour, 6 * hour]
+    );
+    assert_eq!(out.is_sorted_flag(), IsSorted::Ascending);
+}
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_time_range_with_midpoints() {
+    let start = NaiveTime::from_hms_opt(1, 0, 0).unwrap();
+    let end = NaiveTime::from_hms_opt(3, 0, 0).unwrap();
+    let out = time_range_with_midpoints(
+        "time",
+        start,
+        end,
+        Duration::parse("1h"),
+        ClosedWindow::Both,
+    )
+    .unwrap();
+    let half_hour = 1_800_000_000_000i64;
+    assert_eq!(
+        out.cont_slice().unwrap(),
+        &[
+            2 * half_hour,
+            3 * half_hour,
+            4 * half_hour,
+            5 * half_hour,
+            6 * half_hour
+        ]
+    );
+    assert_eq!(out.is_sorted_flag(), IsSorted::Ascending);
+}
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_time_range_n() {
+    let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+    let hour = 3_600_000_000_000i64;
+
+    let out = time_range_n(
+        "time",
+        midnight,
+        Duration::parse("1h"),
+        5,
+        ClosedWindow::Both,
+    )
+    .unwrap();
+    assert_eq!(out.len(), 5);
+    assert_eq!(
+        out.cont_slice().unwrap(),
+        &[0, hour, 2 * hour, 3 * hour, 4 * hour]
+    );
+    // The last point is 04:00.
+    assert_eq!(format!("{}", out.into_series().get(4).unwrap()), "04:00:00");
+
+    // Without the start, the range begins one interval later.
+    let out = time_range_n(
+        "time",
+        midnight,
+        Duration::parse("1h"),
+        2,
+        ClosedWindow::Right,
+    )
+    .unwrap();
+    assert_eq!(out.cont_slice().unwrap(), &[hour, 2 * hour]);
+
+    let out = time_range_n(
+        "time",
+        midnight,
+        Duration::parse("1h"),
+        0,
+        ClosedWindow::Both,
+    )
+    .unwrap();
+    assert!(out.is_empty());
+
+    assert!(time_range_n(
+        "time",
+        midnight,
+        Duration::parse("1h"),
+        25,
+        ClosedWindow::Both
+    )
+    .is_err());
+}
+
+#[test]
+#[cfg(feature = "dtype-time")]
+fn test_time_range_interval_sign_mismatch() {
+    let one = NaiveTime::from_hms_opt(1, 0, 0).unwrap();
+    let three = NaiveTime::from_hms_opt(3, 0, 0).unwrap();
+
+    // Ascending range with a negative interval.
+    let err = time_range(
+        "time",
+        one,
+        three,
+        Duration::parse("-1h"),
+        ClosedWindow::Both,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("interval sign does not match range direction (start=01:00:00, end=03:00:00"));
+
+    // Descending range with a positive interval.
+    let err = time_range(
+        "time",
+        three,
+        one,
+        Duration::parse("1h"),
+        ClosedWindow::Both,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("interval sign does not match range direction (start=03:00:00, end=01:00:00"));
+}
+
+#[test]
+fn test_date_range_monthly_across_february() -> PolarsResult<()> {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    for (year, feb_end) in [(2024, 29), (2023, 28)] {
+        let out = date_range_dates(
+            "date",
+            ymd(year, 1, 31),
+            ymd(year, 4, 30),
+            Duration::parse("1mo"),
+            ClosedWindow::Both,
+        )?;
+        let expected = [
+            ymd(year, 1, 31),
+            ymd(year, 2, feb_end),
+            ymd(year, 3, 31),
+            ymd(year, 4, 30),
+        ];
+        let out = out.as_date_iter().map(Option::unwrap).collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    let out = date_range_dates(
+        "date",
+        ymd(2020, 2, 29),
+        ymd(2024, 2, 29),
+        Duration::parse("1y"),
+        ClosedWindow::Both,
+    )?;
+    let out = out.as_date_iter().map(Option::unwrap).collect::<Vec<_>>();
+    assert_eq!(
+        out,
+        [
+            ymd(2020, 2, 29),
+            ymd(2021, 2, 28),
+            ymd(2022, 2, 28),
+            ymd(2023, 2, 28),
+            ymd(2024, 2, 29),
+        ]
+    );
+    Ok(())
+}
@@ -1417,18 +1417,15 @@ impl FromPyObject<'_> for Wrap<IndexOrder> {
 
 impl FromPyObject<'_> for Wrap<QuantileInterpolOptions> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
-        let parsed = match ob.extract::<&str>()? {
-            "lower" => QuantileInterpolOptions::Lower,
-            "higher" => QuantileInterpolOptions::Higher,
-            "nearest" => QuantileInterpolOptions::Nearest,
-            "linear" => QuantileInterpolOptions::Linear,
-            "midpoint" => QuantileInterpolOptions::Midpoint,
-            v => {
-                return Err(PyValueError::new_err(format!(
-                    "`interpolation` must be one of {{'lower', 'higher', 'nearest', 'linear', 'midpoint'}}, got {v}",
-                )))
-            }
-        };
+        let parsed = ob
+            .extract::<&str>()?
+            .parse::<QuantileInterpolOptions>()
+            .map_err(|_| {
+                PyValueError::new_err(format!(
+                    "`interpolation` must be one of {{'lower', 'higher', 'nearest', 'linear', 'midpoint'}}, got {}",
+                    ob
+                ))
+            })?;
         Ok(Wrap(parsed))
     }
 }
@@ -4,8 +4,8 @@ use std::io::{Error, ErrorKind};
 use polars::prelude::PolarsError;
 use polars_error::PolarsWarning;
 use pyo3::exceptions::{
-    PyException, PyFileExistsError, PyFileNotFoundError, PyIOError, PyPermissionError,
-    PyRuntimeError, PyUserWarning, PyWarning,
+    PyException, PyFileExistsError, PyFileNotFoundError, PyIOError, PyMemoryError,
+    PyPermissionError, PyRuntimeError, PyUserWarning, PyWarning,
 };
 use pyo3::prelude::*;
 use pyo3::{create_exception, PyTypeInfo};
@@ -47,6 +47,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 },
                 PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
                 PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
+                PolarsError::OutOfMemory(err) => PyMemoryError::new_err(err.to_string()),
                 PolarsError::SchemaFieldNotFound(name) => {
                     SchemaFieldNotFoundError::new_err(name.to_string())
                 },